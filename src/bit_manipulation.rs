@@ -1,6 +1,9 @@
 use crate::windows;
-use std::{mem, ops::BitOrAssign};
-use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
+use std::{
+    mem,
+    ops::{BitAnd, BitOrAssign},
+};
+use windows::Win32::Foundation::{COLORREF, LPARAM, LRESULT, WPARAM};
 
 #[inline]
 pub fn build_bit_flag_set<I, T>(flag_translations: I) -> T
@@ -21,6 +24,93 @@ where
     bit_flag_set
 }
 
+#[inline]
+pub fn test_bit_flags<I, T>(bit_flag_set: T, flags: I) -> Vec<bool>
+where
+    I: IntoIterator<Item = T>,
+    T: Copy + BitAnd<Output = T> + PartialEq,
+{
+    //! Translates bits in a bit set to booleans. The counterpart of [`build_bit_flag_set()`].
+    //!
+    //! Each boolean reports whether all bits of the respective flag are set.
+
+    flags
+        .into_iter()
+        .map(|flag_bit_mask| bit_flag_set & flag_bit_mask == flag_bit_mask)
+        .collect()
+}
+
+#[inline]
+pub fn make_word(low: u8, high: u8) -> u16 {
+    //! Like the `MAKEWORD()` macro.
+
+    u16::from_low_high_u8(low, high)
+}
+
+#[inline]
+pub fn make_long(low: u16, high: u16) -> u32 {
+    //! Like the `MAKELONG()` macro.
+
+    u32::from_low_high_u16(low, high)
+}
+
+#[cfg(feature = "f_Win32_Foundation")]
+#[inline]
+pub fn make_wparam(low: u16, high: u16) -> WPARAM {
+    //! Like the `MAKEWPARAM()` macro.
+
+    WPARAM::from_low_high_u16(low, high)
+}
+
+#[cfg(feature = "f_Win32_Foundation")]
+#[inline]
+pub fn make_lparam(low: u16, high: u16) -> LPARAM {
+    //! Like the `MAKELPARAM()` macro. For coordinates, see [`Width32BitPortion::from_low_high_i16()`].
+
+    LPARAM::from_low_high_u16(low, high)
+}
+
+#[cfg(feature = "f_Win32_Foundation")]
+#[inline]
+pub fn rgb(red: u8, green: u8, blue: u8) -> COLORREF {
+    //! Like the `RGB()` macro. The bytes are stored in the order `0x00bbggrr`.
+
+    COLORREF(u32::from_low_high_u16(
+        u16::from_low_high_u8(red, green),
+        blue as u16,
+    ))
+}
+
+#[cfg(feature = "f_Win32_Foundation")]
+pub trait ColorRefExt {
+    /// Like the `GetRValue()` macro.
+    fn red(self) -> u8;
+
+    /// Like the `GetGValue()` macro.
+    fn green(self) -> u8;
+
+    /// Like the `GetBValue()` macro.
+    fn blue(self) -> u8;
+}
+
+#[cfg(feature = "f_Win32_Foundation")]
+impl ColorRefExt for COLORREF {
+    #[inline]
+    fn red(self) -> u8 {
+        self.0.low_u16().low_u8()
+    }
+
+    #[inline]
+    fn green(self) -> u8 {
+        self.0.low_u16().high_u8()
+    }
+
+    #[inline]
+    fn blue(self) -> u8 {
+        self.0.high_u16().low_u8()
+    }
+}
+
 /// A trait concerned with the least significant 16 bits of integer types.
 pub trait Width16BitPortion
 where
@@ -148,3 +238,195 @@ macro_rules! impl_width_32_portion_for_ptr_sized_1_tuple {
 
 #[cfg(feature = "f_Win32_Foundation")]
 impl_width_32_portion_for_ptr_sized_1_tuple!(WPARAM, LPARAM, LRESULT);
+
+/// A trait concerned with the least significant 64 bits of integer types.
+pub trait Width64BitPortion
+where
+    Self: Sized,
+{
+    fn from_low_high_u32(low: u32, high: u32) -> Self;
+
+    #[inline]
+    fn from_high_low_u32(high: u32, low: u32) -> Self {
+        Self::from_low_high_u32(low, high)
+    }
+
+    fn low_u32(self) -> u32;
+    fn high_u32(self) -> u32;
+}
+
+macro_rules! impl_width_64_portion {
+    ($($type:ty),*) => {
+        $(
+            impl Width64BitPortion for $type {
+                #[inline]
+                fn from_low_high_u32(low: u32, high: u32) -> Self {
+                    ((low as u64 & 0xffff_ffff) | ((high as u64 & 0xffff_ffff) << 32)) as Self
+                }
+
+                #[inline]
+                fn low_u32(self) -> u32 {
+                    (self as u64 & 0xffff_ffff) as u32
+                }
+
+                #[inline]
+                fn high_u32(self) -> u32 {
+                    (self as u64 >> 32 & 0xffff_ffff) as u32
+                }
+            }
+        )*
+    };
+}
+
+impl_width_64_portion!(u64, i64, usize, isize);
+
+#[inline]
+pub fn extract_bits<T: BitRange>(value: T, start: u32, len: u32) -> T {
+    //! Returns `len` bits of `value` starting at bit `start`, shifted to the least significant end. See [`BitRange`].
+
+    value.bit_range(start, len)
+}
+
+#[inline]
+pub fn set_bits<T: BitRange>(value: T, start: u32, len: u32, bits: T) -> T {
+    //! Returns `value` with `len` bits starting at bit `start` replaced by the least significant bits of `bits`. Surplus bits of `bits` are ignored. See [`BitRange`].
+
+    value.with_bit_range(start, len, bits)
+}
+
+/// A trait concerned with arbitrary bit ranges of unsigned integer types, like the bit fields in the `lParam` of keyboard messages. Used by [`extract_bits()`] and [`set_bits()`].
+///
+/// Bit 0 is the least significant bit. The range must lie within the type's width, which is only checked by a debug assertion.
+pub trait BitRange
+where
+    Self: Sized,
+{
+    fn bit_range(self, start: u32, len: u32) -> Self;
+    fn with_bit_range(self, start: u32, len: u32, bits: Self) -> Self;
+}
+
+macro_rules! impl_bit_range {
+    ($($type:ty),*) => {
+        $(
+            impl BitRange for $type {
+                #[inline]
+                fn bit_range(self, start: u32, len: u32) -> Self {
+                    debug_assert!(start + len <= Self::BITS, "bit range out of bounds");
+
+                    let mask = Self::MAX.checked_shr(Self::BITS - len).unwrap_or(0);
+                    self.checked_shr(start).unwrap_or(0) & mask
+                }
+
+                #[inline]
+                fn with_bit_range(self, start: u32, len: u32, bits: Self) -> Self {
+                    debug_assert!(start + len <= Self::BITS, "bit range out of bounds");
+
+                    let mask = Self::MAX
+                        .checked_shr(Self::BITS - len)
+                        .unwrap_or(0)
+                        .checked_shl(start)
+                        .unwrap_or(0);
+                    (self & !mask) | (bits.checked_shl(start).unwrap_or(0) & mask)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_range!(u8, u16, u32, u64, usize);
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{
+        build_bit_flag_set, extract_bits, make_long, make_lparam, make_word, make_wparam, rgb,
+        set_bits, test_bit_flags, ColorRefExt, Width32BitPortion, Width64BitPortion,
+    };
+    use crate::windows;
+    use windows::Win32::UI::Shell::{NIF_ICON, NIF_INFO, NIF_MESSAGE, NIF_SHOWTIP, NIF_TIP};
+
+    #[test]
+    fn test_bit_flags_roundtrip() {
+        let flags = [NIF_ICON, NIF_MESSAGE, NIF_TIP, NIF_SHOWTIP, NIF_INFO];
+
+        for needed in [
+            [true, false, true, true, false],
+            [false, true, false, false, true],
+            [false; 5],
+            [true; 5],
+        ] {
+            let bit_flag_set = build_bit_flag_set(needed.into_iter().zip(flags));
+            assert_eq!(test_bit_flags(bit_flag_set, flags), needed);
+        }
+    }
+
+    #[test]
+    fn make_fns() {
+        assert_eq!(make_word(0x34, 0x12), 0x1234);
+        assert_eq!(make_long(0x5678, 0x1234), 0x1234_5678);
+
+        let wparam = make_wparam(0x5678, 0x1234);
+        assert_eq!(wparam.0, 0x1234_5678);
+        assert_eq!((wparam.low_u16(), wparam.high_u16()), (0x5678, 0x1234));
+
+        let lparam = make_lparam(0xffff, 0x8000);
+        assert_eq!((lparam.low_i16(), lparam.high_i16()), (-1, i16::MIN));
+    }
+
+    #[test]
+    fn rgb_color_ref() {
+        let color_ref = rgb(0x56, 0x34, 0x12);
+        assert_eq!(color_ref.0, 0x123456);
+        assert_eq!(
+            (color_ref.red(), color_ref.green(), color_ref.blue()),
+            (0x56, 0x34, 0x12)
+        );
+    }
+
+    #[test]
+    fn width_64_portion() {
+        const VALUE: u64 = 0xDEAD_BEEF_CAFE_F00D;
+
+        assert_eq!(VALUE.low_u32(), 0xCAFE_F00D);
+        assert_eq!(VALUE.high_u32(), 0xDEAD_BEEF);
+        assert_eq!(u64::from_low_high_u32(0xCAFE_F00D, 0xDEAD_BEEF), VALUE);
+        assert_eq!(u64::from_high_low_u32(0xDEAD_BEEF, 0xCAFE_F00D), VALUE);
+
+        assert_eq!((VALUE as i64).high_u32(), 0xDEAD_BEEF);
+        assert_eq!(
+            i64::from_low_high_u32(0xCAFE_F00D, 0xDEAD_BEEF),
+            VALUE as i64
+        );
+        assert_eq!(i64::from_low_high_u32(u32::MAX, u32::MAX), -1);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!((VALUE as usize).low_u32(), 0xCAFE_F00D);
+            assert_eq!((VALUE as isize).high_u32(), 0xDEAD_BEEF);
+            assert_eq!(
+                usize::from_low_high_u32(0xCAFE_F00D, 0xDEAD_BEEF),
+                VALUE as usize
+            );
+        }
+    }
+
+    #[test]
+    fn extract_and_set_bits() {
+        // Key-down `lParam` with repeat count 3, scan code 0x1e and previous key state bit.
+        let lparam = 0x401e_0003_u32;
+        assert_eq!(extract_bits(lparam, 0, 16), 3);
+        assert_eq!(extract_bits(lparam, 16, 8), 0x1e);
+        assert_eq!(extract_bits(lparam, 30, 1), 1);
+        assert_eq!(extract_bits(lparam, 31, 1), 0);
+        assert_eq!(extract_bits(lparam, 0, 32), lparam);
+        assert_eq!(extract_bits(lparam, 0, 0), 0);
+
+        assert_eq!(
+            set_bits(set_bits(0_u32, 0, 16, 3), 16, 8, 0x1e),
+            0x001e_0003
+        );
+        assert_eq!(set_bits(lparam, 16, 8, 0x4d), 0x404d_0003);
+        assert_eq!(set_bits(lparam, 31, 1, 0xff), 0xc01e_0003); // Surplus bits ignored.
+        assert_eq!(set_bits(0xff_u8, 2, 3, 0), 0b1110_0011);
+        assert_eq!(set_bits(0_u8, 0, 8, 0xab), 0xab);
+    }
+}
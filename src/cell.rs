@@ -1,11 +1,13 @@
 use std::{
-    cell::{Cell, Ref, RefCell, RefMut},
+    cell::{BorrowMutError, Cell, Ref, RefCell, RefMut},
     panic::{self, AssertUnwindSafe},
 };
 
 /// A `RefCell` that allows to recursively retrieve a mutable reference.
 ///
 /// Like [`std::cell::RefCell`], but with an additional [`Self::borrow_mut_reentrant()`] method. (If needed, the type could call through to more of `RefCell`'s other methods.)
+///
+/// The reentrant borrowing methods are `unsafe`, because inner calls hand out a second `&mut T` while the outer one still exists. This is only sound, if the outer reference isn't used across the reentrance causing call, which the compiler can't check (see the safety section of [`Self::borrow_mut_reentrant()`]).
 pub struct ReentrantRefCell<T: ?Sized> {
     num_mut_re_borrows: Cell<usize>,
     // `RefCell` not implementing `Sync` will make the struct not implement it either.
@@ -23,6 +25,18 @@ impl<T> ReentrantRefCell<T> {
         }
     }
 
+    pub fn into_inner(self) -> T {
+        #![inline]
+
+        self.ref_cell.into_inner()
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        #![inline]
+
+        self.ref_cell.get_mut()
+    }
+
     pub fn borrow(&self) -> Ref<T> {
         #![inline]
 
@@ -35,6 +49,14 @@ impl<T> ReentrantRefCell<T> {
         self.ref_cell.borrow_mut()
     }
 
+    pub fn reentrant_depth(&self) -> usize {
+        //! Returns the number of currently active (nested) calls of [`Self::borrow_mut_reentrant()`] and [`Self::try_borrow_mut_reentrant()`]. 0 means that no reentrant borrow is active.
+        //!
+        //! For debugging purposes, like asserting that a window procedure isn't reentered deeper than expected.
+
+        self.num_mut_re_borrows.get()
+    }
+
     pub unsafe fn borrow_mut_reentrant<F, U>(&self, f: F) -> U
     where
         F: FnOnce(&mut T) -> U,
@@ -54,21 +76,79 @@ impl<T> ReentrantRefCell<T> {
         //! [2]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroywindow
         //! [3]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-movewindow
 
+        self.try_borrow_mut_reentrant(f).expect("already borrowed")
+    }
+
+    pub unsafe fn try_borrow_mut_reentrant<F, U>(&self, f: F) -> Result<U, BorrowMutError>
+    where
+        F: FnOnce(&mut T) -> U,
+    {
+        //! Like [`Self::borrow_mut_reentrant()`], but returns `Err` instead of panicking, if the outermost call can't borrow, because a conflicting borrow exists (made without this method). Inner calls always succeed.
+        //!
+        //! Lets, e.g., window procedure code decide how to handle the conflict, like by returning `None` to fall back to `DefWindowProcW()`.
+        //!
+        //! # Safety
+        //! See [`Self::borrow_mut_reentrant()`].
+
+        if self.num_mut_re_borrows.get() == 0 {
+            let mut ref_mut = self.ref_cell.try_borrow_mut()?;
+            Ok(self.counting_re_borrow(|| f(&mut ref_mut)))
+        } else {
+            Ok(self.counting_re_borrow(|| f(&mut *self.ref_cell.as_ptr())))
+        }
+    }
+
+    fn counting_re_borrow<F, U>(&self, f: F) -> U
+    where
+        F: FnOnce() -> U,
+    {
         let prev_num_mut_re_borrows = self
             .num_mut_re_borrows
             .replace(self.num_mut_re_borrows.get() + 1);
 
         // Because a caught panic is resumed below, unwind safety can be asserted. (A panic in the middle of mutating the data or whatever the closure closes over may leave some data broken. But since the panic is resumed, it's as if the panic wasn't caught.)
-        let f_retval = panic::catch_unwind(AssertUnwindSafe(|| {
-            if prev_num_mut_re_borrows == 0 {
-                f(&mut *self.ref_cell.borrow_mut())
-            } else {
-                f(&mut *self.ref_cell.as_ptr())
-            }
-        }));
+        let f_retval = panic::catch_unwind(AssertUnwindSafe(f));
 
         self.num_mut_re_borrows.replace(prev_num_mut_re_borrows);
 
         f_retval.unwrap_or_else(|panic_payload| panic::resume_unwind(panic_payload))
     }
 }
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::ReentrantRefCell;
+
+    #[test]
+    fn try_borrow_mut_reentrant() {
+        let cell = ReentrantRefCell::new(1);
+
+        let retval = unsafe {
+            cell.try_borrow_mut_reentrant(|outer| {
+                *outer += 1;
+                assert_eq!(cell.reentrant_depth(), 1);
+
+                cell.try_borrow_mut_reentrant(|inner| {
+                    *inner += 1;
+                    assert_eq!(cell.reentrant_depth(), 2);
+                    *inner
+                })
+            })
+        };
+        assert_eq!(cell.reentrant_depth(), 0);
+        assert_eq!(retval.unwrap().unwrap(), 3);
+
+        let borrow = cell.borrow();
+        assert!(unsafe { cell.try_borrow_mut_reentrant(|_| ()) }.is_err());
+        drop(borrow);
+
+        assert!(unsafe { cell.try_borrow_mut_reentrant(|_| ()) }.is_ok());
+    }
+
+    #[test]
+    fn get_mut_and_into_inner() {
+        let mut cell = ReentrantRefCell::new(vec![1]);
+        cell.get_mut().push(2);
+        assert_eq!(cell.into_inner(), [1, 2]);
+    }
+}
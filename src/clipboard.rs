@@ -0,0 +1,148 @@
+#![cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_System_DataExchange",
+    feature = "f_Win32_System_Memory"
+))]
+
+use crate::{foundation::BoolExt, windows, GlobalLockGuard, ResGuard};
+use std::{mem, ptr, slice};
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::{HANDLE, HGLOBAL, HWND},
+        System::{
+            DataExchange::{
+                CloseClipboard, EmptyClipboard, GetClipboardData, IsClipboardFormatAvailable,
+                OpenClipboard, SetClipboardData,
+            },
+            Memory::{GlobalAlloc, GlobalSize, GMEM_MOVEABLE},
+        },
+    },
+};
+
+/// From `Win32::System::Ole`, to not require another feature.
+const CF_UNICODETEXT: u32 = 13;
+
+/// Opens the clipboard on construction and closes it when dropped.
+///
+/// Only one window can have the clipboard open at a time, so the instance should be short-lived.
+pub struct Clipboard {
+    // Prevent construction without `Self::open()`.
+    _private: (),
+}
+
+impl Clipboard {
+    pub fn open(hwnd: HWND) -> windows::core::Result<Self> {
+        //! Calls [`OpenClipboard()`][1].
+        //!
+        //! To be able to set data, `hwnd` must be a window of yours, since the clipboard owner is set to null otherwise, which makes `SetClipboardData()` fail.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-openclipboard
+
+        unsafe { OpenClipboard(hwnd) }.ok_or_win32_err()?;
+
+        Ok(Self { _private: () })
+    }
+
+    pub fn set_text<T>(&self, text: T) -> windows::core::Result<()>
+    where
+        T: Into<HSTRING>,
+    {
+        //! Empties the clipboard and puts the text on it in the `CF_UNICODETEXT` format.
+
+        let text = text.into();
+        let wide_chars = text.as_wide();
+
+        let h_global = ResGuard::with_acq_and_global_free(|| unsafe {
+            GlobalAlloc(
+                GMEM_MOVEABLE,
+                (wide_chars.len() + 1) * mem::size_of::<u16>(),
+            )
+        })?;
+
+        {
+            let lock = GlobalLockGuard::lock(*h_global)?;
+            let dest = lock.cast::<u16>();
+
+            unsafe {
+                ptr::copy_nonoverlapping(wide_chars.as_ptr(), dest, wide_chars.len());
+                *dest.add(wide_chars.len()) = 0;
+            }
+        }
+
+        // Only empty the clipboard when its new content is ready, so that it isn't lost on failure.
+        unsafe { EmptyClipboard() }.ok_or_win32_err()?;
+
+        // The system owns the memory after success.
+        h_global.release_on_success(|h_global| unsafe {
+            SetClipboardData(CF_UNICODETEXT, HANDLE(h_global.0 as _))
+        })?;
+
+        Ok(())
+    }
+
+    pub fn get_text(&self) -> windows::core::Result<Option<String>> {
+        //! Returns the text in the `CF_UNICODETEXT` format (which the system also synthesizes from other text formats), or `None` if there's no text on the clipboard.
+
+        #[cfg(feature = "windows_v0_48")]
+        let is_available = unsafe { IsClipboardFormatAvailable(CF_UNICODETEXT) }.as_bool();
+        #[cfg(not(feature = "windows_v0_48"))]
+        let is_available = unsafe { IsClipboardFormatAvailable(CF_UNICODETEXT) }.is_ok();
+
+        if !is_available {
+            return Ok(None);
+        }
+
+        let h_global = HGLOBAL(unsafe { GetClipboardData(CF_UNICODETEXT) }?.0 as _);
+        let lock = GlobalLockGuard::lock(h_global)?;
+
+        // The memory block may be larger than requested, so the text ends at the first null character.
+        let wide_chars = unsafe {
+            slice::from_raw_parts(
+                lock.cast::<u16>(),
+                GlobalSize(h_global) / mem::size_of::<u16>(),
+            )
+        };
+        let len = wide_chars
+            .iter()
+            .position(|&wide_char| wide_char == 0)
+            .unwrap_or(wide_chars.len());
+
+        Ok(Some(String::from_utf16(&wide_chars[..len])?))
+    }
+}
+
+impl Drop for Clipboard {
+    fn drop(&mut self) {
+        let _ = unsafe { CloseClipboard() };
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::Clipboard;
+    use crate::{
+        win32_app::window::{Window, WindowClass},
+        windows,
+    };
+
+    #[ignore] // Overwrites the clipboard contents.
+    #[test]
+    fn set_and_get_text() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_msg_only(&class)?;
+
+        let clipboard = Clipboard::open(window.hwnd())?;
+        clipboard.set_text("Äpfel 🍎")?;
+        assert_eq!(clipboard.get_text()?.as_deref(), Some("Äpfel 🍎"));
+        drop(clipboard);
+
+        // Other `Clipboard` instance.
+        assert_eq!(
+            Clipboard::open(window.hwnd())?.get_text()?.as_deref(),
+            Some("Äpfel 🍎")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::windows;
+use std::{ffi::c_void, ops::Deref};
+use windows::{core::Interface, Win32::Foundation::E_POINTER};
+
+/// Holds a COM interface that is released with `IUnknown::Release()` when the guard is dropped.
+///
+/// For when you're working with raw COM interface pointers, below the level of the `windows` crate's interface types. Like with [`crate::ResGuard`], the guard can be treated as the contained interface. The release call happens through the vtable of the interface type the guard was created for, which must be derived from `IUnknown`.
+pub struct ComGuard<I: Interface> {
+    interface: I,
+}
+
+impl<I: Interface> ComGuard<I> {
+    pub unsafe fn from_raw(raw: *mut c_void) -> windows::core::Result<Self> {
+        //! Takes ownership of a raw COM interface pointer. Returns `Err` with [`HRESULT`](windows::core::HRESULT) [`E_POINTER`](windows::Win32::Foundation::E_POINTER), if it's null.
+        //!
+        //! # Safety
+        //! The pointer must be a valid, owned pointer to an interface of type `I`, whose reference count the guard will decrement on drop.
+
+        if raw.is_null() {
+            Err(E_POINTER.into())
+        } else {
+            Ok(Self {
+                interface: I::from_raw(raw),
+            })
+        }
+    }
+
+    pub fn as_raw(&self) -> *mut c_void {
+        //! Returns the raw COM interface pointer, which continues to be owned by the guard.
+
+        self.interface.as_raw()
+    }
+
+    pub fn release(self) -> *mut c_void {
+        //! Returns the raw COM interface pointer without decrementing the reference count.
+        //!
+        //! For cases in which ownership of the pointer is transferred.
+
+        self.interface.into_raw()
+    }
+}
+
+impl<I: Interface> Deref for ComGuard<I> {
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        &self.interface
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::ComGuard;
+    use crate::windows;
+    use windows::{
+        core::{IUnknown, Interface},
+        Win32::{
+            System::Com::{
+                CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+            },
+            UI::Shell::ShellLink,
+        },
+    };
+
+    #[test]
+    fn from_raw() -> windows::core::Result<()> {
+        unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.ok()?;
+
+        let unknown: IUnknown =
+            unsafe { CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER) }?;
+        let ref_count = || unsafe {
+            (unknown.vtable().AddRef)(unknown.as_raw());
+            (unknown.vtable().Release)(unknown.as_raw())
+        };
+
+        // Raw pointer with its own reference.
+        let raw = unknown.clone().into_raw();
+        assert_eq!(ref_count(), 2);
+
+        let unknown_guard = unsafe { ComGuard::<IUnknown>::from_raw(raw) }?;
+        assert_eq!(unknown_guard.as_raw(), unknown.as_raw());
+        assert_eq!(ref_count(), 2);
+
+        drop(unknown_guard);
+        assert_eq!(ref_count(), 1);
+
+        Ok(())
+    }
+}
@@ -3,3 +3,18 @@ mod string;
 
 pub use error::*;
 pub use string::*;
+
+/// Looks up the name of the first listed constant that equals the value, for functions like `message_name()` and `win32_error_name()`.
+///
+/// The constants are compared instead of being used as `match` patterns, so that a name missing from a `windows` crate version fails to compile instead of becoming a catch-all binding.
+macro_rules! const_name {
+    ($value:expr; $($name:ident,)*) => {{
+        let value = $value;
+        [$(($name, stringify!($name)),)*]
+            .into_iter()
+            .find(|(constant, _)| *constant == value)
+            .map(|(_, name)| name)
+    }};
+}
+
+pub(crate) use const_name;
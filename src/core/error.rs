@@ -1,7 +1,9 @@
 use crate::{windows, Null, ValidateHandle};
 use windows::{
     core::HRESULT,
-    Win32::Foundation::{E_FAIL, E_HANDLE},
+    Win32::Foundation::{
+        ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, ERROR_SUCCESS, E_FAIL, E_HANDLE, WIN32_ERROR,
+    },
 };
 
 pub trait ResultExt<T> {
@@ -11,6 +13,11 @@ pub trait ResultExt<T> {
     /// Returns `Err` with [`windows::core::Error::from_win32()`].
     fn err_from_win32() -> windows::core::Result<T>;
 
+    /// Returns `Ok(())`, if the code is `ERROR_SUCCESS`, or otherwise `Err` with the code converted to an `HRESULT`.
+    ///
+    /// To be used with functions like `RegOpenKeyExW()` that return a Win32 error code instead of calling `SetLastError()`.
+    fn from_win32_code(code: WIN32_ERROR) -> windows::core::Result<()>;
+
     /// Passes a `T` through to an `Ok` value, if the check is successful, or otherwise returns `Err` with [`windows::core::Error::from_win32()`].
     ///
     /// Can be used transitionally in new cases, until this crate might offer a more suitable solution.
@@ -42,6 +49,14 @@ impl<T> ResultExt<T> for windows::core::Result<T> {
         Err(windows::core::Error::from_win32())
     }
 
+    fn from_win32_code(code: WIN32_ERROR) -> windows::core::Result<()> {
+        if code == ERROR_SUCCESS {
+            Ok(())
+        } else {
+            Err(code.to_hresult().into())
+        }
+    }
+
     fn from_checked_or_win32<F>(t: T, check: F) -> windows::core::Result<T>
     where
         F: FnOnce(&T) -> bool,
@@ -117,6 +132,52 @@ where
     }
 }
 
+pub trait CheckSentinelError
+where
+    Self: num_traits::PrimInt,
+{
+    /// Passes `self` through to an `Ok` value, if it differs from `sentinel`, or otherwise returns `Err` with [`windows::core::Error::from_win32()`].
+    ///
+    /// Like [`CheckNumberError::nonzero_or_win32_err()`], but for functions like `SetFilePointer()` that signal failure with a value other than 0, often `u32::MAX` (like `INVALID_SET_FILE_POINTER`). If the sentinel can also be a valid value (like `INVALID_FILE_SIZE` with `GetFileSize()`), [`ResultExt::from_win32()`] must be used instead, possibly after `SetLastError(ERROR_SUCCESS)`.
+    fn not_sentinel_or_win32_err(self, sentinel: Self) -> windows::core::Result<Self>;
+}
+
+impl<T> CheckSentinelError for T
+where
+    T: num_traits::PrimInt,
+{
+    fn not_sentinel_or_win32_err(self, sentinel: Self) -> windows::core::Result<Self> {
+        if self == sentinel {
+            Err(windows::core::Error::from_win32())
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+pub trait CheckFloatError
+where
+    Self: num_traits::Float,
+{
+    /// Passes a finite `self` through to an `Ok` value, or, in case of it being NaN or infinite, returns `Err` with [`HRESULT`](windows::core::HRESULT) [`E_FAIL`](windows::Win32::Foundation::E_FAIL).
+    ///
+    /// For functions without an `HRESULT` that return a floating-point value and signal failure with NaN or infinity, like some audio and graphics APIs. (Checking for zero is possible with [`CheckNumberError`]. The `NonZero*` integer types don't need checking.)
+    fn finite_or_e_fail(self) -> windows::core::Result<Self>;
+}
+
+impl<T> CheckFloatError for T
+where
+    T: num_traits::Float,
+{
+    fn finite_or_e_fail(self) -> windows::core::Result<Self> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(E_FAIL.into())
+        }
+    }
+}
+
 pub trait CheckNullError
 where
     Self: Null + Sized,
@@ -148,6 +209,11 @@ where
     ///
     /// To be used with functions that don't offer an error code via `GetLastError()`, and when there's a need to validate with `is_invalid()`.
     fn valid_or_e_handle(self) -> windows::core::Result<Self>;
+
+    /// Passes a `self`, if successfully validated with `is_invalid()`, through to an `Ok` value, or, in case of it being invalid, returns `Err` with [`windows::core::Error::from_win32()`].
+    ///
+    /// To be used with functions like `CreateFileW()` that return `INVALID_HANDLE_VALUE` (-1) on failure and offer an error code via `GetLastError()`, in contrast to the `E_HANDLE` functions. `HANDLE::is_invalid()` treats both `INVALID_HANDLE_VALUE` and null as invalid, while [`CheckNullError::nonnull_or_e_handle()`] would let `INVALID_HANDLE_VALUE` pass.
+    fn valid_or_win32_err(self) -> windows::core::Result<Self>;
 }
 
 impl<T> CheckHandleError for T
@@ -161,6 +227,14 @@ where
             Ok(self)
         }
     }
+
+    fn valid_or_win32_err(self) -> windows::core::Result<Self> {
+        if self.is_invalid() {
+            Err(windows::core::Error::from_win32())
+        } else {
+            Ok(self)
+        }
+    }
 }
 
 pub trait HResultExt {
@@ -168,6 +242,18 @@ pub trait HResultExt {
     ///
     /// [1]: https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-assocquerystringw
     fn ok_with_hresult(self) -> windows::core::Result<HRESULT>;
+
+    /// Whether the `HRESULT` is the one that [`WIN32_ERROR::to_hresult()`](windows::Win32::Foundation::WIN32_ERROR) yields for the error code (like `HRESULT_FROM_WIN32()`). Useful for branching on `error.code()` in [`crate::dual_call()`] closures and elsewhere.
+    fn is_win32_error(&self, win32_error: WIN32_ERROR) -> bool;
+
+    /// Whether the `HRESULT` corresponds to `ERROR_INSUFFICIENT_BUFFER`.
+    fn is_insufficient_buffer(&self) -> bool;
+
+    /// Whether the `HRESULT` corresponds to `ERROR_MORE_DATA`.
+    fn is_more_data(&self) -> bool;
+
+    /// Extracts the Win32 error code, if the `HRESULT` is in the range of `HRESULT_FROM_WIN32()`, i.e., is an error with facility `FACILITY_WIN32`. The inverse of [`WIN32_ERROR::to_hresult()`](windows::Win32::Foundation::WIN32_ERROR) for error codes.
+    fn win32_error_code(&self) -> Option<WIN32_ERROR>;
 }
 
 impl HResultExt for HRESULT {
@@ -178,19 +264,118 @@ impl HResultExt for HRESULT {
             Err(self.into())
         }
     }
+
+    fn is_win32_error(&self, win32_error: WIN32_ERROR) -> bool {
+        *self == win32_error.to_hresult()
+    }
+
+    fn is_insufficient_buffer(&self) -> bool {
+        self.is_win32_error(ERROR_INSUFFICIENT_BUFFER)
+    }
+
+    fn is_more_data(&self) -> bool {
+        self.is_win32_error(ERROR_MORE_DATA)
+    }
+
+    fn win32_error_code(&self) -> Option<WIN32_ERROR> {
+        let value = self.0 as u32;
+        (value & 0xffff_0000 == 0x8007_0000).then_some(WIN32_ERROR(value & 0xffff))
+    }
+}
+
+pub fn win32_error_name(code: WIN32_ERROR) -> Option<&'static str> {
+    //! Returns the name of a common Win32 error code, like `"ERROR_FILE_NOT_FOUND"`, for debugging purposes.
+
+    use windows::Win32::Foundation::*;
+
+    super::const_name!(code;
+        ERROR_SUCCESS,
+        ERROR_INVALID_FUNCTION,
+        ERROR_FILE_NOT_FOUND,
+        ERROR_PATH_NOT_FOUND,
+        ERROR_TOO_MANY_OPEN_FILES,
+        ERROR_ACCESS_DENIED,
+        ERROR_INVALID_HANDLE,
+        ERROR_NOT_ENOUGH_MEMORY,
+        ERROR_INVALID_DATA,
+        ERROR_OUTOFMEMORY,
+        ERROR_NO_MORE_FILES,
+        ERROR_WRITE_PROTECT,
+        ERROR_NOT_READY,
+        ERROR_SHARING_VIOLATION,
+        ERROR_LOCK_VIOLATION,
+        ERROR_HANDLE_EOF,
+        ERROR_NOT_SUPPORTED,
+        ERROR_FILE_EXISTS,
+        ERROR_INVALID_PARAMETER,
+        ERROR_BROKEN_PIPE,
+        ERROR_BUFFER_OVERFLOW,
+        ERROR_INSUFFICIENT_BUFFER,
+        ERROR_INVALID_NAME,
+        ERROR_MOD_NOT_FOUND,
+        ERROR_PROC_NOT_FOUND,
+        ERROR_DIR_NOT_EMPTY,
+        ERROR_BAD_PATHNAME,
+        ERROR_BUSY,
+        ERROR_ALREADY_EXISTS,
+        ERROR_FILENAME_EXCED_RANGE,
+        ERROR_ENVVAR_NOT_FOUND,
+        ERROR_MORE_DATA,
+        ERROR_NO_MORE_ITEMS,
+        ERROR_DIRECTORY,
+        ERROR_PARTIAL_COPY,
+        ERROR_INVALID_ADDRESS,
+        ERROR_OPERATION_ABORTED,
+        ERROR_IO_INCOMPLETE,
+        ERROR_IO_PENDING,
+        ERROR_NOACCESS,
+        ERROR_TIMEOUT,
+        ERROR_INVALID_FLAGS,
+        ERROR_NO_TOKEN,
+        ERROR_INVALID_WINDOW_HANDLE,
+        ERROR_INVALID_MENU_HANDLE,
+        ERROR_CLASS_ALREADY_EXISTS,
+        ERROR_CLASS_DOES_NOT_EXIST,
+        ERROR_INVALID_THREAD_ID,
+        ERROR_CANCELLED,
+        ERROR_NOT_FOUND,
+        ERROR_DATATYPE_MISMATCH,
+        ERROR_PRIVILEGE_NOT_HELD,
+        ERROR_ELEVATION_REQUIRED,
+    )
+}
+
+pub fn describe(error: &windows::core::Error) -> String {
+    //! Formats the error like its `Display` impl, but, if its `HRESULT` stems from a Win32 error code, appends the code with its name from [`win32_error_name()`], like `"ERROR_INSUFFICIENT_BUFFER (0x7A)"`.
+
+    match error.code().win32_error_code() {
+        Some(code) => match win32_error_name(code) {
+            Some(name) => format!("{error} [{name} ({:#X})]", code.0),
+            None => format!("{error} [Win32 error {:#X}]", code.0),
+        },
+        None => error.to_string(),
+    }
 }
 
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
     use crate::{
-        core::{CheckNumberError, HResultExt},
-        windows,
+        core::{
+            describe, CheckFloatError, CheckHandleError, CheckNumberError, CheckSentinelError,
+            HResultExt, ResultExt,
+        },
+        windows, Null,
     };
     use windows::Win32::{
-        Foundation::{ERROR_INSUFFICIENT_BUFFER, E_FAIL, E_UNEXPECTED, S_FALSE, S_OK},
+        Foundation::{
+            SetLastError, ERROR_FILE_NOT_FOUND, ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_HANDLE,
+            ERROR_MORE_DATA, ERROR_SUCCESS, E_FAIL, E_UNEXPECTED, HANDLE, INVALID_HANDLE_VALUE,
+            S_FALSE, S_OK, WIN32_ERROR,
+        },
         Globalization::{
             GetLocaleInfoEx, LOCALE_ICURRDIGITS, LOCALE_NAME_INVARIANT, LOCALE_RETURN_NUMBER,
         },
+        Storage::FileSystem::{SetFilePointer, FILE_BEGIN, INVALID_SET_FILE_POINTER},
     };
 
     #[test]
@@ -223,6 +408,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn not_sentinel_or_win32_err() {
+        assert_eq!(
+            unsafe { SetFilePointer(HANDLE::NULL, 0, None, FILE_BEGIN) }
+                .not_sentinel_or_win32_err(INVALID_SET_FILE_POINTER),
+            Err(ERROR_INVALID_HANDLE.into())
+        );
+
+        assert_eq!(5_i32.not_sentinel_or_win32_err(-1), Ok(5));
+        assert_eq!(0_u32.not_sentinel_or_win32_err(u32::MAX), Ok(0));
+    }
+
+    #[test]
+    fn finite_or_e_fail() {
+        assert_eq!(1.5_f64.finite_or_e_fail(), Ok(1.5));
+        assert_eq!(0.0_f64.finite_or_e_fail(), Ok(0.0));
+        assert_eq!((-2.0_f32).finite_or_e_fail(), Ok(-2.0));
+        assert_eq!(f64::NAN.finite_or_e_fail(), Err(E_FAIL.into()));
+        assert_eq!(f64::INFINITY.finite_or_e_fail(), Err(E_FAIL.into()));
+        assert_eq!(f32::NEG_INFINITY.finite_or_e_fail(), Err(E_FAIL.into()));
+
+        // Checking for zero works with floats, too.
+        assert_eq!(0.0_f64.nonzero_or_e_fail(), Err(E_FAIL.into()));
+    }
+
+    #[test]
+    fn describe_win32_error() {
+        assert_eq!(
+            ERROR_INSUFFICIENT_BUFFER.to_hresult().win32_error_code(),
+            Some(ERROR_INSUFFICIENT_BUFFER)
+        );
+        assert_eq!(E_FAIL.win32_error_code(), None);
+        assert_eq!(S_OK.win32_error_code(), None);
+
+        let error = windows::core::Error::from(ERROR_INSUFFICIENT_BUFFER);
+        assert!(describe(&error).ends_with(" [ERROR_INSUFFICIENT_BUFFER (0x7A)]"));
+        assert!(describe(&error).starts_with(&error.to_string()));
+
+        let error = windows::core::Error::from(WIN32_ERROR(0xfffe));
+        assert!(describe(&error).ends_with(" [Win32 error 0xFFFE]"));
+
+        let error = windows::core::Error::from(E_FAIL);
+        assert_eq!(describe(&error), error.to_string());
+    }
+
+    #[test]
+    fn from_win32_code() {
+        assert_eq!(
+            windows::core::Result::<()>::from_win32_code(ERROR_SUCCESS),
+            Ok(())
+        );
+        assert_eq!(
+            windows::core::Result::<()>::from_win32_code(ERROR_FILE_NOT_FOUND),
+            Err(ERROR_FILE_NOT_FOUND.into())
+        );
+    }
+
+    #[test]
+    fn valid_or_win32_err() {
+        for handle in [INVALID_HANDLE_VALUE, HANDLE::NULL] {
+            unsafe { SetLastError(ERROR_FILE_NOT_FOUND) };
+            assert_eq!(
+                handle.valid_or_win32_err(),
+                Err(ERROR_FILE_NOT_FOUND.into())
+            );
+        }
+
+        let handle = HANDLE(4 as _);
+        assert_eq!(handle.valid_or_win32_err(), Ok(handle));
+    }
+
     #[test]
     fn hresult_ext_ok_with_hresult() {
         assert_eq!(S_OK.ok_with_hresult(), Ok(S_OK));
@@ -230,4 +486,17 @@ mod tests {
         assert_eq!(E_FAIL.ok_with_hresult(), Err(E_FAIL.into()));
         assert_eq!(E_UNEXPECTED.ok_with_hresult(), Err(E_UNEXPECTED.into()));
     }
+
+    #[test]
+    fn hresult_ext_predicates() {
+        let error: windows::core::Error = ERROR_INSUFFICIENT_BUFFER.into();
+        assert!(error.code().is_insufficient_buffer());
+        assert!(error.code().is_win32_error(ERROR_INSUFFICIENT_BUFFER));
+        assert!(!error.code().is_more_data());
+        assert!(!error.code().is_win32_error(ERROR_FILE_NOT_FOUND));
+
+        assert!(ERROR_MORE_DATA.to_hresult().is_more_data());
+        assert!(!S_OK.is_insufficient_buffer());
+        assert!(!E_FAIL.is_more_data());
+    }
 }
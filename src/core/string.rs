@@ -1,5 +1,8 @@
 use crate::windows;
-use windows::core::HSTRING;
+use windows::{
+    core::{HSTRING, PCWSTR, PWSTR},
+    Win32::Foundation::E_POINTER,
+};
 
 pub trait HStringExt {
     /// Similar to `HSTRING::as_wide()`, but truncates the slice to the specified length, avoiding to cut a UTF-16 surrogate pair in half by reducing the length by one additional wide char, if needed.
@@ -8,7 +11,20 @@ pub trait HStringExt {
     fn as_safely_truncated_wide(&self, target_len: usize) -> &[u16];
 
     /// Writes the `HSTRING` into the buffer, followed by a terminating null character.
+    ///
+    /// With an empty buffer, nothing is written.
     fn write_truncated(&self, buf: &mut [u16]);
+
+    /// Returns an owned copy of the wide chars, followed by a terminating null character.
+    ///
+    /// For passing the string as a `PCWSTR` while making the buffer's lifetime explicit on the caller's side.
+    fn to_wide_null(&self) -> Vec<u16>;
+
+    /// Compares the strings case-insensitively with `CompareStringOrdinal()`, which uses the operating system's locale-independent uppercase table, like the file system and the registry do. Unlike `str::eq_ignore_ascii_case()`, this also works for non-ASCII characters. No multi-character foldings are applied, so `ß` and `SS` don't match.
+    ///
+    /// Activate feature `windows_<version>_f_Win32_Globalization`.
+    #[cfg(feature = "f_Win32_Globalization")]
+    fn eq_ignore_case(&self, other: &HSTRING) -> bool;
 }
 
 impl HStringExt for HSTRING {
@@ -35,14 +51,200 @@ impl HStringExt for HSTRING {
     }
 
     fn write_truncated(&self, buf: &mut [u16]) {
+        if buf.is_empty() {
+            return;
+        }
+
         let truncated_slice =
             self.as_safely_truncated_wide(self.len().min(buf.len() - 1 /*null-termination*/));
 
         buf[..truncated_slice.len()].copy_from_slice(truncated_slice);
         buf[truncated_slice.len()] = 0;
     }
+
+    fn to_wide_null(&self) -> Vec<u16> {
+        let mut vec = Vec::with_capacity(self.len() + 1);
+        vec.extend_from_slice(self.as_wide());
+        vec.push(0);
+
+        vec
+    }
+
+    #[cfg(feature = "f_Win32_Globalization")]
+    fn eq_ignore_case(&self, other: &HSTRING) -> bool {
+        use windows::Win32::Globalization::{CompareStringOrdinal, CSTR_EQUAL};
+
+        let result = unsafe { CompareStringOrdinal(self.as_wide(), other.as_wide(), true) };
+        #[cfg(feature = "windows_v0_48")]
+        let is_equal = result == CSTR_EQUAL as i32;
+        #[cfg(not(feature = "windows_v0_48"))]
+        let is_equal = result == CSTR_EQUAL;
+
+        is_equal
+    }
+}
+
+pub trait PcwstrExt {
+    /// Converts the string up to its terminating null character to a `String`, replacing invalid UTF-16 with `U+FFFD`. Returns `Err` with [`HRESULT`](windows::core::HRESULT) [`E_POINTER`](windows::Win32::Foundation::E_POINTER), if the pointer is null.
+    ///
+    /// # Safety
+    /// The pointer must be null or point to a valid null-terminated wide string.
+    unsafe fn to_string_lossy_checked(self) -> windows::core::Result<String>;
+
+    /// Like [`Self::to_string_lossy_checked()`], but additionally trims trailing whitespace, like the line break at the end of messages from `FormatMessageW()`.
+    ///
+    /// # Safety
+    /// The pointer must be null or point to a valid null-terminated wide string.
+    unsafe fn to_string_trimmed(self) -> windows::core::Result<String>;
+}
+
+impl PcwstrExt for PCWSTR {
+    unsafe fn to_string_lossy_checked(self) -> windows::core::Result<String> {
+        if self.is_null() {
+            Err(E_POINTER.into())
+        } else {
+            Ok(String::from_utf16_lossy(self.as_wide()))
+        }
+    }
+
+    unsafe fn to_string_trimmed(self) -> windows::core::Result<String> {
+        let mut string = self.to_string_lossy_checked()?;
+        string.truncate(string.trim_end().len());
+
+        Ok(string)
+    }
+}
+
+/// Like [`PcwstrExt`].
+pub trait PwstrExt {
+    /// See [`PcwstrExt::to_string_lossy_checked()`].
+    ///
+    /// # Safety
+    /// The pointer must be null or point to a valid null-terminated wide string.
+    unsafe fn to_string_lossy_checked(self) -> windows::core::Result<String>;
+
+    /// See [`PcwstrExt::to_string_trimmed()`].
+    ///
+    /// # Safety
+    /// The pointer must be null or point to a valid null-terminated wide string.
+    unsafe fn to_string_trimmed(self) -> windows::core::Result<String>;
+}
+
+impl PwstrExt for PWSTR {
+    unsafe fn to_string_lossy_checked(self) -> windows::core::Result<String> {
+        PCWSTR(self.0).to_string_lossy_checked()
+    }
+
+    unsafe fn to_string_trimmed(self) -> windows::core::Result<String> {
+        PCWSTR(self.0).to_string_trimmed()
+    }
 }
 
 const fn is_leading_surrogate(wide_char: u16) -> bool {
     wide_char >= 0xd800 && wide_char <= 0xdbff
 }
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{HStringExt, PcwstrExt, PwstrExt};
+    use crate::{core::CheckNumberError, windows, Null, ResGuard};
+    use windows::{
+        core::{HSTRING, PCWSTR, PWSTR},
+        Win32::{
+            Foundation::{ERROR_FILE_NOT_FOUND, E_POINTER},
+            System::Diagnostics::Debug::{
+                FormatMessageW, FORMAT_MESSAGE_ALLOCATE_BUFFER, FORMAT_MESSAGE_FROM_SYSTEM,
+                FORMAT_MESSAGE_IGNORE_INSERTS,
+            },
+        },
+    };
+
+    #[test]
+    fn eq_ignore_case() {
+        let eq = |a: &str, b: &str| HSTRING::from(a).eq_ignore_case(&HSTRING::from(b));
+
+        assert!(eq(r"C:\Program Files", r"c:\PROGRAM FILES"));
+        assert!(!eq("abc", "abcd"));
+        assert!(eq("", ""));
+
+        // Non-ASCII, unlike `eq_ignore_ascii_case()`.
+        assert!(!"Äpfel".eq_ignore_ascii_case("äPFEL"));
+        assert!(eq("Äpfel", "äPFEL"));
+        assert!(eq("ΣΊΣΥΦΟΣ", "σίσυφοσ"));
+
+        // Locale-independent: No Turkish dotted/dotless I rules.
+        assert!(eq("i", "I"));
+        assert!(!eq("i", "İ"));
+
+        // No multi-character folding.
+        assert!(!eq("Straße", "STRASSE"));
+    }
+
+    #[test]
+    fn to_wide_null() -> windows::core::Result<()> {
+        const STRING: &str = "Ünïcödé 🦀";
+
+        let wide_null = HSTRING::from(STRING).to_wide_null();
+        assert_eq!(wide_null.last(), Some(&0));
+        assert_eq!(
+            wide_null
+                .iter()
+                .filter(|&&wide_char| wide_char == 0)
+                .count(),
+            1
+        );
+        assert_eq!(
+            String::from_utf16(&wide_null[..wide_null.len() - 1])?,
+            STRING
+        );
+
+        assert_eq!(HSTRING::new().to_wide_null(), [0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_truncated_empty_buf() {
+        HSTRING::from("text").write_truncated(&mut []);
+    }
+
+    #[test]
+    fn pwstr_to_string() -> windows::core::Result<()> {
+        let message = ResGuard::<PWSTR>::with_mut_acq_and_local_free(|pwstr| {
+            unsafe {
+                FormatMessageW(
+                    FORMAT_MESSAGE_ALLOCATE_BUFFER
+                        | FORMAT_MESSAGE_FROM_SYSTEM
+                        | FORMAT_MESSAGE_IGNORE_INSERTS,
+                    None,
+                    ERROR_FILE_NOT_FOUND.0,
+                    0,
+                    PWSTR((pwstr as *mut PWSTR).cast()),
+                    0,
+                    None,
+                )
+            }
+            .nonzero_or_win32_err()
+        })?;
+
+        let untrimmed = unsafe { message.to_string_lossy_checked()? };
+        let trimmed = unsafe { message.to_string_trimmed()? };
+        assert!(untrimmed.ends_with("\r\n"));
+        assert!(!trimmed.is_empty() && trimmed == untrimmed.trim_end());
+
+        assert_eq!(
+            unsafe { PCWSTR::NULL.to_string_lossy_checked() }
+                .unwrap_err()
+                .code(),
+            E_POINTER
+        );
+        assert_eq!(
+            unsafe { PWSTR::NULL.to_string_trimmed() }
+                .unwrap_err()
+                .code(),
+            E_POINTER
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_UI_HiDpi"))]
+
+use crate::{core::CheckNumberError, foundation::BoolExt, windows};
+use windows::Win32::{
+    Foundation::HWND,
+    UI::HiDpi::{GetDpiForWindow, SetProcessDpiAwarenessContext, DPI_AWARENESS_CONTEXT},
+};
+
+/// The DPI that corresponds to a scale factor of 100 %.
+pub const DEFAULT_DPI: u32 = 96;
+
+pub fn set_process_dpi_awareness_context(
+    context: DPI_AWARENESS_CONTEXT,
+) -> windows::core::Result<()> {
+    //! Calls [`SetProcessDpiAwarenessContext()`][1], e.g., with `DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2`. Must be called before creating windows.
+    //!
+    //! Declaring the DPI awareness in the app manifest is recommended instead. The function fails with `ERROR_ACCESS_DENIED`, if the awareness was already set, e.g., by the manifest.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext
+
+    unsafe { SetProcessDpiAwarenessContext(context) }.ok_or_win32_err()
+}
+
+pub fn dpi_for_window(hwnd: HWND) -> windows::core::Result<u32> {
+    //! Calls [`GetDpiForWindow()`][1]. The result depends on the DPI awareness of the window. Fails with `E_FAIL`, if the `HWND` is invalid.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow
+
+    unsafe { GetDpiForWindow(hwnd) }.nonzero_or_e_fail()
+}
+
+pub fn scale_for_dpi(value: i32, dpi: u32) -> i32 {
+    //! Scales a value, like a length in pixels at 100 % scaling, from [`DEFAULT_DPI`] to the DPI. Rounds like `MulDiv()`.
+
+    (value as f64 * dpi as f64 / DEFAULT_DPI as f64).round() as _
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{dpi_for_window, scale_for_dpi, DEFAULT_DPI};
+    use crate::{windows, Null};
+    use windows::Win32::{Foundation::HWND, UI::WindowsAndMessaging::GetDesktopWindow};
+
+    #[test]
+    fn dpi_for_window_and_scale() {
+        let dpi = dpi_for_window(unsafe { GetDesktopWindow() }).unwrap();
+        assert!(dpi >= DEFAULT_DPI);
+
+        assert!(dpi_for_window(HWND::NULL).is_err());
+
+        assert_eq!(scale_for_dpi(16, DEFAULT_DPI), 16);
+        assert_eq!(scale_for_dpi(16, 144), 24);
+        assert_eq!(scale_for_dpi(-10, 120), -13); // -12.5
+        assert_eq!(scale_for_dpi(7, 120), 9); // 8.75
+    }
+}
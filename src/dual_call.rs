@@ -1,12 +1,12 @@
 use crate::windows;
 use windows::{
     core::HRESULT,
-    Win32::Foundation::{E_UNEXPECTED, WIN32_ERROR},
+    Win32::Foundation::{ERROR_SUCCESS, E_UNEXPECTED, WIN32_ERROR},
 };
 
 pub fn dual_call<F, T>(
     first_call_expectation: FirstCallExpectation<T>,
-    mut call: F,
+    call: F,
 ) -> windows::core::Result<T>
 where
     F: FnMut(bool) -> windows::core::Result<T>,
@@ -20,37 +20,117 @@ where
     //!
     //! If the expectation after the first call isn't met and it returned an `Err`, the function ends with that `Err`. If the first call returned `Ok`, however, and this didn't harmonize with the expectation, `Err` including `HRESULT` `E_UNEXPECTED` is returned.
 
+    retrying_call(first_call_expectation, 2, call)
+}
+
+pub fn dual_call_counted<F, T>(
+    first_call_expectation: FirstCallExpectation<T>,
+    num_calls: &mut usize,
+    mut call: F,
+) -> windows::core::Result<T>
+where
+    F: FnMut(bool) -> windows::core::Result<T>,
+    T: PartialEq,
+{
+    //! Like [`dual_call()`], but writes the number of calls made to `num_calls`, also when returning `Err`. 1 means that the function ended after the first call, because the expectation wasn't met.
+    //!
+    //! Useful for diagnostics, e.g., when chasing a bug with a required buffer size that changes between the calls.
+
+    *num_calls = 0;
+
+    dual_call(first_call_expectation, |getting_buffer_size| {
+        *num_calls += 1;
+        call(getting_buffer_size)
+    })
+}
+
+pub fn dual_call_sized<F1, F2, T>(
+    first_call_expectation: FirstCallExpectation<T>,
+    mut size_of: F1,
+    mut call: F2,
+) -> windows::core::Result<(Vec<u8>, T)>
+where
+    F1: FnMut(&mut u32) -> windows::core::Result<T>,
+    F2: FnMut(&mut [u8], &mut u32) -> windows::core::Result<T>,
+    T: PartialEq,
+{
+    //! Like [`dual_call()`], but owns the buffer and its size in bytes, so that you don't have to manage them outside of the closures.
+    //!
+    //! The first closure is called for the first call and must write the required buffer size in bytes to its parameter. The second closure receives the buffer resized accordingly, along with the size variable, which the function may update. Afterwards, the buffer is truncated to that size and returned with the value of the second call.
+
+    let mut buffer = Vec::new();
+    let mut buffer_size = 0;
+
+    let value = dual_call(first_call_expectation, |getting_buffer_size| {
+        if getting_buffer_size {
+            size_of(&mut buffer_size)
+        } else {
+            buffer.resize(buffer_size as _, 0);
+            call(&mut buffer, &mut buffer_size)
+        }
+    })?;
+
+    buffer.truncate(buffer_size as _);
+
+    Ok((buffer, value))
+}
+
+pub fn retrying_call<F, T>(
+    first_call_expectation: FirstCallExpectation<T>,
+    max_num_calls: usize,
+    mut call: F,
+) -> windows::core::Result<T>
+where
+    F: FnMut(bool) -> windows::core::Result<T>,
+    T: PartialEq,
+{
+    //! Like [`dual_call()`], but repeats the second call as long as it fails with the error expected from the first call, until `max_num_calls` calls were made in total.
+    //!
+    //! For functions with which the required buffer size can change between the calls, like with [`GetAdaptersAddresses()`][1]. The closure parameter will only be `true` for the first call. When the maximum number of calls was reached, the result of the last call is returned, which may be the expected `Err`. With an expectation of `Ok`, `OkValue` or `OkWithPredicate`, the second call isn't repeated. A `max_num_calls` below 2 is treated as 2.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/iphlpapi/nf-iphlpapi-getadaptersaddresses
+
     match first_call_expectation {
         FirstCallExpectation::Ok => {
             call(true)?;
-            call(false)
         }
-        FirstCallExpectation::OkValue(expected_value) => {
-            if call(true)? == expected_value {
-                call(false)
-            } else {
-                Err(E_UNEXPECTED.into())
+        FirstCallExpectation::OkValue(ref expected_value) => {
+            if call(true)? != *expected_value {
+                return Err(E_UNEXPECTED.into());
             }
         }
-        other_expectation => {
-            let expected_h_result = match other_expectation {
-                FirstCallExpectation::Win32Error(win_32_error) => win_32_error.to_hresult(),
-                FirstCallExpectation::HResultError(h_result) => h_result,
-                _ => unreachable!(),
-            };
-
-            match call(true) {
-                Err(error) => {
-                    if error.code() == expected_h_result {
-                        call(false)
-                    } else {
-                        Err(error)
-                    }
+        FirstCallExpectation::OkWithPredicate(ref predicate) => {
+            let value = call(true)?;
+            if !predicate(&value) {
+                return Ok(value);
+            }
+        }
+        _ => match call(true) {
+            Err(error) => {
+                if !first_call_expectation.is_expected_error(&error) {
+                    return Err(error);
+                }
+            }
+            Ok(_) => {
+                if !first_call_expectation.is_expected_success() {
+                    return Err(E_UNEXPECTED.into());
                 }
-                Ok(_) => Err(E_UNEXPECTED.into()),
             }
+        },
+    }
+
+    let mut result = call(false);
+
+    for _ in 2..max_num_calls {
+        match result {
+            Err(ref error) if first_call_expectation.is_expected_error(error) => {
+                result = call(false);
+            }
+            _ => break,
         }
     }
+
+    result
 }
 
 /// Defining the return value of the first call of [`dual_call()`] that is the precondition to continue with the second call.
@@ -66,20 +146,54 @@ pub enum FirstCallExpectation<T> {
     /// [1]: https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-assocquerystringw
     OkValue(T),
 
+    /// Like `Ok`, but the second call is only made if the predicate returns `true` for the value of the first call. Otherwise, that value is returned right away.
+    ///
+    /// For functions that succeed with the sizing call, but whose size (which the closure can return as its value) may be 0 when there's no data to retrieve.
+    OkWithPredicate(Box<dyn Fn(&T) -> bool>),
+
     /// The most useful. Requires `ERROR_INSUFFICIENT_BUFFER` most often, if not documented.
     Win32Error(WIN32_ERROR),
 
+    /// Like `Win32Error`, but for functions that can return one of several errors. If `ERROR_SUCCESS` is included, an `Ok` from the first call is also accepted.
+    ///
+    /// Useful with a function like [`RegQueryValueExW()`][1].
+    ///
+    /// [1]: https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regqueryvalueexw
+    Win32ErrorOneOf(&'static [WIN32_ERROR]),
+
     /// Useful with a function like [`AssocQueryStringW()`][1] (in `ASSOCF_NOTRUNCATE` mode).
     ///
     /// [1]: https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-assocquerystringw
     HResultError(HRESULT),
 }
 
+impl<T> FirstCallExpectation<T> {
+    fn is_expected_error(&self, error: &windows::core::Error) -> bool {
+        match *self {
+            Self::Win32Error(win_32_error) => error.code() == win_32_error.to_hresult(),
+            Self::Win32ErrorOneOf(win_32_errors) => win_32_errors
+                .iter()
+                .any(|win_32_error| error.code() == win_32_error.to_hresult()),
+            Self::HResultError(h_result) => error.code() == h_result,
+            _ => false,
+        }
+    }
+
+    fn is_expected_success(&self) -> bool {
+        match *self {
+            Self::Win32ErrorOneOf(win_32_errors) => win_32_errors.contains(&ERROR_SUCCESS),
+            _ => false,
+        }
+    }
+}
+
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
-    use super::{dual_call, FirstCallExpectation};
+    use super::{
+        dual_call, dual_call_counted, dual_call_sized, retrying_call, FirstCallExpectation,
+    };
     use crate::{
-        core::{CheckNumberError, HResultExt},
+        core::{CheckNumberError, HResultExt, ResultExt},
         windows, Null, ResGuard,
     };
     use regex::Regex;
@@ -87,8 +201,8 @@ mod tests {
         core::{w, PCWSTR, PWSTR},
         Win32::{
             Foundation::{
-                ERROR_BUFFER_OVERFLOW, ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, E_FAIL,
-                E_POINTER, S_FALSE, S_OK, WIN32_ERROR,
+                ERROR_BUFFER_OVERFLOW, ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, ERROR_SUCCESS,
+                E_FAIL, E_POINTER, S_FALSE, S_OK, WIN32_ERROR,
             },
             NetworkManagement::IpHelper::{
                 GetAdaptersAddresses, GET_ADAPTERS_ADDRESSES_FLAGS, IP_ADAPTER_ADDRESSES_LH,
@@ -99,6 +213,7 @@ mod tests {
                 SID_AND_ATTRIBUTES, TOKEN_QUERY,
             },
             System::{
+                Registry::{RegOpenKeyExW, RegQueryValueExW, HKEY_LOCAL_MACHINE, KEY_READ},
                 SystemInformation::{ComputerNameNetBIOS, GetComputerNameExW},
                 Threading::{GetCurrentProcess, OpenProcessToken},
             },
@@ -203,6 +318,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dual_call_sized_token_user() -> windows::core::Result<()> {
+        let process_token_handle = ResGuard::with_mut_acq_and_close_handle(|handle| unsafe {
+            OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, handle)
+        })?;
+
+        let (sid_and_attrs_buffer, _) = dual_call_sized(
+            FirstCallExpectation::Win32Error(ERROR_INSUFFICIENT_BUFFER),
+            |buffer_size| unsafe {
+                GetTokenInformation(*process_token_handle, TokenUser, None, 0, buffer_size)
+            },
+            |buffer, buffer_size| unsafe {
+                GetTokenInformation(
+                    *process_token_handle,
+                    TokenUser,
+                    Some(buffer.as_mut_ptr().cast()),
+                    buffer.len() as _,
+                    buffer_size,
+                )
+            },
+        )?;
+
+        assert!(sid_and_attrs_buffer.len() >= std::mem::size_of::<SID_AND_ATTRIBUTES>());
+
+        let string_sid = unsafe {
+            ResGuard::<PWSTR>::with_mut_acq_and_local_free(|pwstr| {
+                ConvertSidToStringSidW(
+                    (&*sid_and_attrs_buffer.as_ptr().cast::<SID_AND_ATTRIBUTES>()).Sid,
+                    pwstr,
+                )
+            })?
+            .to_string()?
+        };
+
+        assert!(Regex::new(r"^S-1-5(?:-\d+)+$")
+            .unwrap()
+            .is_match(&string_sid));
+
+        Ok(())
+    }
+
     #[test]
     fn expect_win32_error_buffer_overflow_from_return() -> windows::core::Result<()> {
         let mut byte_buffer = Vec::<u8>::new();
@@ -211,7 +367,7 @@ mod tests {
         dual_call(
             FirstCallExpectation::Win32Error(ERROR_BUFFER_OVERFLOW),
             |getting_buffer_size| {
-                WIN32_ERROR(unsafe {
+                windows::core::Result::<()>::from_win32_code(WIN32_ERROR(unsafe {
                     GetAdaptersAddresses(
                         AF_UNSPEC.0 as _,
                         GET_ADAPTERS_ADDRESSES_FLAGS(0),
@@ -222,9 +378,7 @@ mod tests {
                         }),
                         &mut buffer_size,
                     )
-                })
-                .to_hresult()
-                .ok()
+                }))
             },
         )?;
 
@@ -252,6 +406,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn expect_win32_error_one_of() -> windows::core::Result<()> {
+        let h_key = ResGuard::with_mut_acq_and_reg_close_key(|h_key| {
+            unsafe {
+                RegOpenKeyExW(
+                    HKEY_LOCAL_MACHINE,
+                    w!(r"SOFTWARE\Microsoft\Windows NT\CurrentVersion"),
+                    0,
+                    KEY_READ,
+                    h_key,
+                )
+            }
+            .ok()
+        })?;
+
+        // Without a buffer, the first call succeeds. With a too small one, it fails with `ERROR_MORE_DATA`.
+        for probe_with_buffer in [false, true] {
+            let mut buffer = vec![0_u8; 1];
+            let mut buffer_size = if probe_with_buffer { 1 } else { 0 };
+
+            dual_call(
+                FirstCallExpectation::Win32ErrorOneOf(&[ERROR_SUCCESS, ERROR_MORE_DATA]),
+                |getting_buffer_size| {
+                    if !getting_buffer_size {
+                        buffer.resize(buffer_size as _, 0);
+                    }
+
+                    unsafe {
+                        RegQueryValueExW(
+                            *h_key,
+                            w!("ProductName"),
+                            None,
+                            None,
+                            (!getting_buffer_size || probe_with_buffer)
+                                .then_some(buffer.as_mut_ptr()),
+                            Some(&mut buffer_size),
+                        )
+                    }
+                    .ok()
+                },
+            )?;
+
+            let wide_chars = buffer[..buffer_size as _]
+                .chunks_exact(2)
+                .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+                .take_while(|&wide_char| wide_char != 0)
+                .collect::<Vec<_>>();
+            assert!(String::from_utf16(&wide_chars)?.starts_with("Windows"));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn expect_ok_value() -> windows::core::Result<()> {
         let mut buffer = Vec::new();
@@ -328,4 +535,117 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn retrying_call_with_stale_buffer_size() -> windows::core::Result<()> {
+        let mut byte_buffer = Vec::<u8>::new();
+        let mut buffer_size = 0;
+        let mut num_calls = 0;
+
+        retrying_call(
+            FirstCallExpectation::Win32Error(ERROR_BUFFER_OVERFLOW),
+            5,
+            |getting_buffer_size| {
+                num_calls += 1;
+
+                // Simulate the required size having grown after the first call.
+                if num_calls == 2 {
+                    buffer_size /= 2;
+                }
+
+                windows::core::Result::<()>::from_win32_code(WIN32_ERROR(unsafe {
+                    GetAdaptersAddresses(
+                        AF_UNSPEC.0 as _,
+                        GET_ADAPTERS_ADDRESSES_FLAGS(0),
+                        None,
+                        (!getting_buffer_size).then(|| {
+                            byte_buffer.resize(buffer_size as _, 0);
+                            byte_buffer.as_mut_ptr().cast()
+                        }),
+                        &mut buffer_size,
+                    )
+                }))
+            },
+        )?;
+
+        assert_eq!(num_calls, 3);
+        assert!(
+            !unsafe { &*byte_buffer.as_ptr().cast::<IP_ADAPTER_ADDRESSES_LH>() }
+                .FriendlyName
+                .is_null()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn retrying_call_max_num_calls() {
+        let mut num_calls = 0;
+
+        let result = retrying_call(
+            FirstCallExpectation::<()>::Win32Error(ERROR_INSUFFICIENT_BUFFER),
+            4,
+            |_| {
+                num_calls += 1;
+                Err(ERROR_INSUFFICIENT_BUFFER.to_hresult().into())
+            },
+        );
+
+        assert_eq!(num_calls, 4);
+        assert_eq!(
+            result.unwrap_err().code(),
+            ERROR_INSUFFICIENT_BUFFER.to_hresult()
+        );
+    }
+
+    #[test]
+    fn dual_call_counted_num_calls() {
+        let mut num_calls = 0;
+
+        let result = dual_call_counted(FirstCallExpectation::Ok, &mut num_calls, |_| Ok(()));
+        assert_eq!(result, Ok(()));
+        assert_eq!(num_calls, 2);
+
+        let result = dual_call_counted(
+            FirstCallExpectation::<()>::Win32Error(ERROR_INSUFFICIENT_BUFFER),
+            &mut num_calls,
+            |_| Err(E_FAIL.into()),
+        );
+        assert_eq!(result, Err(E_FAIL.into()));
+        assert_eq!(num_calls, 1);
+    }
+
+    #[test]
+    fn expect_ok_with_predicate() -> windows::core::Result<()> {
+        // Like an API that reports the data size on success, which is 0 without data.
+        fn get_data(data: &[u16], buffer: Option<&mut [u16]>) -> windows::core::Result<usize> {
+            if let Some(buffer) = buffer {
+                buffer.copy_from_slice(data);
+            }
+            Ok(data.len())
+        }
+
+        for data in [&[1, 2, 3][..], &[]] {
+            let mut buffer = Vec::new();
+            let mut num_calls = 0;
+
+            dual_call_counted(
+                FirstCallExpectation::OkWithPredicate(Box::new(|&len| len != 0)),
+                &mut num_calls,
+                |getting_buffer_size| {
+                    if getting_buffer_size {
+                        get_data(data, None)
+                    } else {
+                        buffer.resize(data.len(), 0);
+                        get_data(data, Some(&mut buffer))
+                    }
+                },
+            )?;
+
+            assert_eq!(buffer, data);
+            assert_eq!(num_calls, if data.is_empty() { 1 } else { 2 });
+        }
+
+        Ok(())
+    }
 }
@@ -2,17 +2,31 @@ use crate::windows;
 use std::mem;
 
 /// For structs that benefit from an alternative to `default()` to be able to write more expressive code.
-pub trait Zeroed {
+///
+/// Implemented for all types implementing [`ZeroableStruct`].
+pub trait Zeroed: ZeroableStruct {
     fn zeroed() -> Self;
 }
 
+impl<T: ZeroableStruct> Zeroed for T {
+    fn zeroed() -> Self {
+        // SAFETY: Guaranteed by the implementor of `ZeroableStruct`.
+        unsafe { mem::zeroed() }
+    }
+}
+
+/// Marker for plain-old-data structs, like the `windows` crate's `#[repr(C)]` structs, that makes [`Zeroed`] available for them.
+///
+/// # Safety
+/// The all-zero bit pattern must be a valid value of the type. This isn't the case, e.g., with references, `Box`, function pointers not wrapped in `Option` and enums without a variant with discriminant 0. The type should also not have drop glue.
+pub unsafe trait ZeroableStruct: Sized {}
+
 macro_rules! impl_zeroed {
     ($type:ty) => {
-        impl Zeroed for $type {
-            fn zeroed() -> Self {
-                unsafe { mem::zeroed() }
-            }
-        }
+        // Catches some unsound cases, like types containing a `Vec`.
+        const _: () = assert!(!mem::needs_drop::<$type>());
+
+        unsafe impl ZeroableStruct for $type {}
     };
 }
 
@@ -22,9 +36,13 @@ pub trait Null {
     fn is_null(&self) -> bool;
 }
 
+/// Implements [`Null`] for a tuple struct whose single field is an integer or a pointer, like `struct Foo(isize)`.
+///
+/// Assumes that the value `0` (`.0 == 0`) means null. Usable with your own FFI handle types to make them work with, e.g., [`crate::core::CheckNullError`] and [`crate::ResGuard`].
+#[macro_export]
 macro_rules! impl_null {
     ($type:ty) => {
-        impl Null for $type {
+        impl $crate::Null for $type {
             const NULL: Self = Self(0 as _);
 
             fn is_null(&self) -> bool {
@@ -39,11 +57,13 @@ pub trait ValidateHandle {
     fn is_invalid(&self) -> bool;
 }
 
+/// Like [`impl_null!`], but additionally implements [`ValidateHandle`] by calling through to an inherent `is_invalid()` method that the type must have (like the `windows` crate's handle types).
+#[macro_export]
 macro_rules! impl_null_and_validate_handle {
     ($type:ty) => {
-        impl_null!($type);
+        $crate::impl_null!($type);
 
-        impl ValidateHandle for $type {
+        impl $crate::ValidateHandle for $type {
             #[inline]
             fn is_invalid(&self) -> bool {
                 <$type>::is_invalid(self)
@@ -57,7 +77,23 @@ macro_rules! impl_null_and_validate_handle {
 #[cfg(feature = "f_Win32_Foundation")]
 impl_zeroed!(windows::Win32::Foundation::POINT);
 #[cfg(feature = "f_Win32_Foundation")]
+impl_zeroed!(windows::Win32::Foundation::RECT);
+#[cfg(feature = "f_Win32_Foundation")]
 impl_zeroed!(windows::Win32::Foundation::SIZE);
+#[cfg(feature = "f_Win32_Foundation")]
+#[cfg(feature = "f_Win32_Graphics_Gdi")]
+impl_zeroed!(windows::Win32::Graphics::Gdi::PAINTSTRUCT);
+#[cfg(feature = "f_Win32_Foundation")]
+#[cfg(feature = "f_Win32_UI_WindowsAndMessaging")]
+#[cfg(feature = "f_Win32_UI_Shell")]
+impl_zeroed!(windows::Win32::UI::Shell::NOTIFYICONDATAW);
+#[cfg(feature = "f_Win32_Foundation")]
+#[cfg(feature = "f_Win32_UI_WindowsAndMessaging")]
+impl_zeroed!(windows::Win32::UI::WindowsAndMessaging::MSG);
+#[cfg(feature = "f_Win32_Foundation")]
+#[cfg(feature = "f_Win32_Graphics_Gdi")]
+#[cfg(feature = "f_Win32_UI_WindowsAndMessaging")]
+impl_zeroed!(windows::Win32::UI::WindowsAndMessaging::WNDCLASSEXW);
 
 // `null()` already available, but not usable with trait bounds.
 impl_null!(windows::core::PCSTR);
@@ -66,6 +102,8 @@ impl_null!(windows::core::PSTR);
 impl_null!(windows::core::PWSTR);
 
 // Types without an official (trait-less) `is_invalid()` method.
+#[cfg(feature = "f_Win32_Foundation")]
+impl_null!(windows::Win32::Foundation::HANDLE_PTR);
 #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
 #[cfg(feature = "f_Win32_Foundation")]
 impl_null!(windows::Win32::Foundation::HWND);
@@ -128,8 +166,10 @@ impl_null_and_validate_handle!(windows::Win32::Foundation::HWND);
 #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
 #[cfg(feature = "f_Win32_Foundation")]
 impl_null_and_validate_handle!(windows::Win32::Foundation::PSID);
+#[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
 #[cfg(feature = "f_Win32_Globalization")]
 impl_null_and_validate_handle!(windows::Win32::Globalization::HIMC);
+#[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
 #[cfg(feature = "f_Win32_Globalization")]
 impl_null_and_validate_handle!(windows::Win32::Globalization::HIMCC);
 #[cfg(feature = "f_Win32_Globalization")]
@@ -509,3 +549,98 @@ impl_null_and_validate_handle!(windows::Win32::UI::WindowsAndMessaging::HHOOK);
 impl_null_and_validate_handle!(windows::Win32::UI::WindowsAndMessaging::HICON);
 #[cfg(feature = "f_Win32_UI_WindowsAndMessaging")]
 impl_null_and_validate_handle!(windows::Win32::UI::WindowsAndMessaging::HMENU);
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use crate::{core::CheckNullError, windows, Null, ValidateHandle, ZeroableStruct, Zeroed};
+    use windows::{
+        core::{s, w, PCSTR, PCWSTR, PSTR, PWSTR},
+        Win32::{
+            Foundation::{E_HANDLE, HANDLE_PTR, RECT},
+            UI::WindowsAndMessaging::{MSG, WNDCLASSEXW},
+        },
+    };
+
+    #[derive(PartialEq, Debug)]
+    struct CustomHandle(isize);
+
+    impl CustomHandle {
+        fn is_invalid(&self) -> bool {
+            self.0 == 0 || self.0 == -1
+        }
+    }
+
+    crate::impl_null_and_validate_handle!(CustomHandle);
+
+    #[test]
+    fn custom_handle() {
+        assert_eq!(CustomHandle::NULL, CustomHandle(0));
+        assert!(CustomHandle(0).is_null());
+        assert!(!CustomHandle(-1).is_null());
+        assert!(ValidateHandle::is_invalid(&CustomHandle(-1)));
+        assert!(!ValidateHandle::is_invalid(&CustomHandle(4)));
+    }
+
+    #[test]
+    fn handle_ptr() {
+        assert!(HANDLE_PTR::NULL.is_null());
+        assert!(!HANDLE_PTR(1).is_null());
+    }
+
+    #[test]
+    fn string_pointers() {
+        assert!(PCSTR::NULL.is_null());
+        assert!(PCWSTR::NULL.is_null());
+        assert!(PSTR::NULL.is_null());
+        assert!(PWSTR::NULL.is_null());
+
+        assert_eq!(PCWSTR::NULL.nonnull_or_e_handle(), Err(E_HANDLE.into()));
+        assert_eq!(PWSTR::NULL.nonnull_or_e_handle(), Err(E_HANDLE.into()));
+
+        // Empty, but not null.
+        let mut narrow_buffer = [0_u8];
+        let mut wide_buffer = [0_u16];
+
+        let pcstr = s!("");
+        assert!(!pcstr.is_null());
+        assert_eq!(pcstr.nonnull_or_e_handle(), Ok(pcstr));
+
+        let pcwstr = w!("");
+        assert!(!pcwstr.is_null());
+        assert_eq!(pcwstr.nonnull_or_e_handle(), Ok(pcwstr));
+
+        let pstr = PSTR(narrow_buffer.as_mut_ptr());
+        assert!(!pstr.is_null());
+        assert_eq!(pstr.nonnull_or_e_handle(), Ok(pstr));
+
+        let pwstr = PWSTR(wide_buffer.as_mut_ptr());
+        assert!(!pwstr.is_null());
+        assert_eq!(pwstr.nonnull_or_e_handle(), Ok(pwstr));
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(RECT::zeroed(), RECT::default());
+        assert_eq!(MSG::zeroed(), MSG::default());
+
+        let wnd_class_ex = WNDCLASSEXW::zeroed();
+        assert_eq!(wnd_class_ex.cbSize, 0);
+        assert!(wnd_class_ex.lpfnWndProc.is_none());
+        assert!(wnd_class_ex.lpszClassName.is_null());
+    }
+
+    #[test]
+    fn zeroed_custom_struct() {
+        #[repr(C)]
+        struct Custom {
+            a: u32,
+            b: Option<extern "system" fn()>,
+        }
+
+        unsafe impl ZeroableStruct for Custom {}
+
+        let custom = Custom::zeroed();
+        assert_eq!(custom.a, 0);
+        assert!(custom.b.is_none());
+    }
+}
@@ -1,11 +1,16 @@
 #![cfg(feature = "f_Win32_Foundation")]
 
-use crate::windows;
-use windows::Win32::Foundation::{E_FAIL, LPARAM};
+use crate::{bit_manipulation::Width32BitPortion, windows};
+use std::mem;
+use windows::Win32::Foundation::{E_FAIL, LPARAM, POINT, RECT, SIZE};
 
+/// Implemented for `BOOL` and, with the newer `windows` versions, for `Result<()>`, because many functions that return `BOOL` with `windows` v0.48 return `Result<()>` with newer versions. This lets calling code read the same with all versions.
 pub trait BoolExt {
     /// Like [`BOOL::ok()`](windows::Win32::Foundation::BOOL::ok), but returning an `Error` with [`HRESULT`](windows::core::HRESULT) [`E_FAIL`](windows::Win32::Foundation::E_FAIL) instead of calling `GetLastError()`.
     fn ok_or_e_fail(self) -> windows::core::Result<()>;
+
+    /// Like [`BOOL::ok()`](windows::Win32::Foundation::BOOL::ok), returning an `Error` with [`windows::core::Error::from_win32()`]. For functions that call `SetLastError()` on failure, like `DestroyWindow()`, in contrast to [`Self::ok_or_e_fail()`].
+    fn ok_or_win32_err(self) -> windows::core::Result<()>;
 }
 
 impl BoolExt for windows::Win32::Foundation::BOOL {
@@ -16,11 +21,42 @@ impl BoolExt for windows::Win32::Foundation::BOOL {
             Err(E_FAIL.into())
         }
     }
+
+    fn ok_or_win32_err(self) -> windows::core::Result<()> {
+        if self.as_bool() {
+            Ok(())
+        } else {
+            Err(windows::core::Error::from_win32())
+        }
+    }
+}
+
+/// For functions that return `Result<()>` with newer `windows` versions, which already calls `GetLastError()` on failure.
+#[cfg(not(feature = "windows_v0_48"))]
+impl BoolExt for windows::core::Result<()> {
+    fn ok_or_e_fail(self) -> windows::core::Result<()> {
+        self.map_err(|_| E_FAIL.into())
+    }
+
+    fn ok_or_win32_err(self) -> windows::core::Result<()> {
+        self
+    }
 }
 
 pub trait LParamExt {
     unsafe fn cast_to_ref<T>(&self) -> &T;
     unsafe fn cast_to_mut<T>(&mut self) -> &mut T;
+
+    /// Like [`Self::cast_to_ref()`], but returns `None` if the `LPARAM` is null. Misalignment for `T` is caught by a debug assertion.
+    ///
+    /// Note that the pointee's size can't be checked.
+    unsafe fn cast_to_ref_checked<T>(&self) -> Option<&T>;
+
+    /// Like the `GET_X_LPARAM()` macro. For mouse messages like `WM_LBUTTONDOWN`, whose coordinates can be negative on multi-monitor setups.
+    fn mouse_x(&self) -> i16;
+
+    /// Like the `GET_Y_LPARAM()` macro.
+    fn mouse_y(&self) -> i16;
 }
 
 impl LParamExt for LPARAM {
@@ -31,4 +67,267 @@ impl LParamExt for LPARAM {
     unsafe fn cast_to_mut<T>(&mut self) -> &mut T {
         &mut *(self.0 as *mut T)
     }
+
+    unsafe fn cast_to_ref_checked<T>(&self) -> Option<&T> {
+        let ptr = self.0 as *const T;
+        debug_assert!(
+            ptr as usize & (mem::align_of::<T>() - 1) == 0,
+            "`LPARAM` pointer misaligned for type"
+        );
+
+        ptr.as_ref()
+    }
+
+    fn mouse_x(&self) -> i16 {
+        self.low_i16()
+    }
+
+    fn mouse_y(&self) -> i16 {
+        self.high_i16()
+    }
+}
+
+/// Like the functions `IsRectEmpty()`, `PtInRect()`, `IntersectRect()` and `UnionRect()`, without requiring the `Win32_Graphics_Gdi` feature. The right and bottom edges are exclusive.
+pub trait RectExt {
+    /// Returns `right - left`, which is negative for an inverted rectangle.
+    fn width(&self) -> i32;
+
+    /// Returns `bottom - top`, which is negative for an inverted rectangle.
+    fn height(&self) -> i32;
+
+    /// Whether the rectangle has no area, which is also the case for an inverted rectangle.
+    fn is_empty(&self) -> bool;
+
+    /// Whether the point is inside the rectangle, excluding the right and bottom edges. Always `false` for an empty rectangle.
+    fn contains(&self, point: POINT) -> bool;
+
+    /// Returns the overlapping area, or `None` if the rectangles don't overlap or one of them is empty.
+    fn intersect(&self, other: RECT) -> Option<RECT>;
+
+    /// Returns the smallest rectangle containing both rectangles. Empty rectangles are ignored, so that the result is `RECT::default()` if both are empty.
+    fn union(&self, other: RECT) -> RECT;
+}
+
+impl RectExt for RECT {
+    fn width(&self) -> i32 {
+        self.right - self.left
+    }
+
+    fn height(&self) -> i32 {
+        self.bottom - self.top
+    }
+
+    fn is_empty(&self) -> bool {
+        self.right <= self.left || self.bottom <= self.top
+    }
+
+    fn contains(&self, point: POINT) -> bool {
+        (self.left..self.right).contains(&point.x) && (self.top..self.bottom).contains(&point.y)
+    }
+
+    fn intersect(&self, other: RECT) -> Option<RECT> {
+        let rect = RECT {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.min(other.right),
+            bottom: self.bottom.min(other.bottom),
+        };
+
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    fn union(&self, other: RECT) -> RECT {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => RECT::default(),
+            (true, false) => other,
+            (false, true) => *self,
+            (false, false) => RECT {
+                left: self.left.min(other.left),
+                top: self.top.min(other.top),
+                right: self.right.max(other.right),
+                bottom: self.bottom.max(other.bottom),
+            },
+        }
+    }
+}
+
+/// Arithmetic for `POINT`, with named methods, since operators can't be implemented for foreign types.
+pub trait PointExt {
+    fn offset(&self, dx: i32, dy: i32) -> POINT;
+    fn add_size(&self, size: SIZE) -> POINT;
+    fn sub_size(&self, size: SIZE) -> POINT;
+    fn add_point(&self, other: POINT) -> POINT;
+
+    /// Returns the distance from `other` to `self` along each axis.
+    fn sub_point(&self, other: POINT) -> SIZE;
+}
+
+impl PointExt for POINT {
+    fn offset(&self, dx: i32, dy: i32) -> POINT {
+        POINT {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+
+    fn add_size(&self, size: SIZE) -> POINT {
+        self.offset(size.cx, size.cy)
+    }
+
+    fn sub_size(&self, size: SIZE) -> POINT {
+        self.offset(-size.cx, -size.cy)
+    }
+
+    fn add_point(&self, other: POINT) -> POINT {
+        self.offset(other.x, other.y)
+    }
+
+    fn sub_point(&self, other: POINT) -> SIZE {
+        SIZE {
+            cx: self.x - other.x,
+            cy: self.y - other.y,
+        }
+    }
+}
+
+/// Like [`PointExt`].
+pub trait SizeExt {
+    fn add_size(&self, other: SIZE) -> SIZE;
+    fn sub_size(&self, other: SIZE) -> SIZE;
+
+    /// Divides both dimensions by 2, rounding towards zero. For centering.
+    fn half(&self) -> SIZE;
+}
+
+impl SizeExt for SIZE {
+    fn add_size(&self, other: SIZE) -> SIZE {
+        SIZE {
+            cx: self.cx + other.cx,
+            cy: self.cy + other.cy,
+        }
+    }
+
+    fn sub_size(&self, other: SIZE) -> SIZE {
+        SIZE {
+            cx: self.cx - other.cx,
+            cy: self.cy - other.cy,
+        }
+    }
+
+    fn half(&self) -> SIZE {
+        SIZE {
+            cx: self.cx / 2,
+            cy: self.cy / 2,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{BoolExt, LParamExt, PointExt, RectExt, SizeExt};
+    use crate::{bit_manipulation::Width32BitPortion, windows};
+    use windows::Win32::Foundation::{
+        SetLastError, BOOL, ERROR_INVALID_WINDOW_HANDLE, E_FAIL, LPARAM, POINT, RECT, SIZE,
+    };
+
+    #[test]
+    fn ok_or_win32_err() {
+        assert_eq!(BOOL(1).ok_or_win32_err(), Ok(()));
+
+        unsafe { SetLastError(ERROR_INVALID_WINDOW_HANDLE) };
+        assert_eq!(
+            BOOL(0).ok_or_win32_err(),
+            Err(ERROR_INVALID_WINDOW_HANDLE.into())
+        );
+    }
+
+    #[test]
+    fn ok_or_e_fail_on_result() {
+        let result: windows::core::Result<()> = Ok(());
+        assert_eq!(result.ok_or_e_fail(), Ok(()));
+
+        let result: windows::core::Result<()> = Err(ERROR_INVALID_WINDOW_HANDLE.into());
+        assert_eq!(result.clone().ok_or_e_fail(), Err(E_FAIL.into()));
+        assert_eq!(
+            result.ok_or_win32_err(),
+            Err(ERROR_INVALID_WINDOW_HANDLE.into())
+        );
+    }
+
+    #[test]
+    fn cast_to_ref_checked() {
+        let value = 5_u64;
+        let lparam = LPARAM(&value as *const u64 as _);
+        assert_eq!(unsafe { lparam.cast_to_ref_checked::<u64>() }, Some(&5));
+        assert_eq!(unsafe { LPARAM(0).cast_to_ref_checked::<u64>() }, None);
+    }
+
+    #[test]
+    fn mouse_x_y() {
+        let lparam = LPARAM::from_low_high_i16(-1920, 540);
+        assert_eq!(lparam.mouse_x(), -1920);
+        assert_eq!(lparam.mouse_y(), 540);
+
+        let lparam = LPARAM::from_low_high_i16(100, -1080);
+        assert_eq!(lparam.mouse_x(), 100);
+        assert_eq!(lparam.mouse_y(), -1080);
+    }
+
+    #[test]
+    fn rect_ext() {
+        let rect = |left, top, right, bottom| RECT {
+            left,
+            top,
+            right,
+            bottom,
+        };
+        let point = |x, y| POINT { x, y };
+
+        let a = rect(0, 0, 100, 50);
+        assert_eq!((a.width(), a.height()), (100, 50));
+        assert!(!a.is_empty());
+        assert!(a.contains(point(0, 0)));
+        assert!(a.contains(point(99, 49)));
+        assert!(!a.contains(point(100, 49))); // Exclusive edges.
+        assert!(!a.contains(point(-1, 0)));
+
+        let inverted = rect(100, 50, 0, 0);
+        assert_eq!((inverted.width(), inverted.height()), (-100, -50));
+        assert!(inverted.is_empty());
+        assert!(!inverted.contains(point(50, 25)));
+
+        let empty = rect(10, 10, 10, 20);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(point(10, 10)));
+
+        let b = rect(50, 25, 150, 75);
+        assert_eq!(a.intersect(b), Some(rect(50, 25, 100, 50)));
+        assert_eq!(a.intersect(rect(100, 0, 200, 50)), None); // Only touching.
+        assert_eq!(a.intersect(inverted), None);
+        assert_eq!(a.intersect(empty), None);
+
+        assert_eq!(a.union(b), rect(0, 0, 150, 75));
+        assert_eq!(a.union(inverted), a);
+        assert_eq!(empty.union(b), b);
+        assert_eq!(empty.union(inverted), RECT::default());
+    }
+
+    #[test]
+    fn point_and_size_ext() {
+        let point = POINT { x: 10, y: -20 };
+        let size = SIZE { cx: 5, cy: 7 };
+
+        assert_eq!(point.offset(-10, 20), POINT { x: 0, y: 0 });
+        assert_eq!(point.add_size(size), POINT { x: 15, y: -13 });
+        assert_eq!(point.sub_size(size), POINT { x: 5, y: -27 });
+        assert_eq!(point.add_point(point), POINT { x: 20, y: -40 });
+        assert_eq!(
+            POINT { x: 15, y: -13 }.sub_point(point),
+            SIZE { cx: 5, cy: 7 }
+        );
+
+        assert_eq!(size.add_size(size), SIZE { cx: 10, cy: 14 });
+        assert_eq!(size.sub_size(size), SIZE::default());
+        assert_eq!(size.half(), SIZE { cx: 2, cy: 3 });
+    }
 }
@@ -1,16 +1,22 @@
 pub mod bit_manipulation;
+pub mod clipboard;
 pub mod core;
+pub mod dpi;
 pub mod foundation;
 pub mod power;
+pub mod registry;
+pub mod shell;
 pub mod win32_app;
 pub mod wnds_and_msging;
 
 mod cell;
+mod com_guard;
 mod dual_call;
 mod empty;
 mod res_guard;
 
 pub use cell::*;
+pub use com_guard::*;
 pub use dual_call::*;
 pub use empty::*;
 pub use res_guard::*;
@@ -1,11 +1,29 @@
 #![cfg(feature = "f_Win32_System_Power")]
 
 use crate::{foundation::BoolExt, windows};
-use std::{mem, ptr};
+use std::{mem, ptr, slice};
 use windows::Win32::{Foundation::BOOL, System::Power::POWERBROADCAST_SETTING};
+#[cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_UI_WindowsAndMessaging"
+))]
+use {
+    crate::ResGuard,
+    windows::{
+        core::GUID,
+        Win32::{
+            Foundation::{HANDLE, HWND},
+            System::Power::{RegisterPowerSettingNotification, HPOWERNOTIFY},
+            UI::WindowsAndMessaging::DEVICE_NOTIFY_WINDOW_HANDLE,
+        },
+    },
+};
 
 pub trait PowerBroadcastSettingExt {
     unsafe fn cast_data<T>(&self) -> windows::core::Result<&T>;
+
+    /// Like [`Self::cast_data()`], but for settings whose data consists of multiple elements. `DataLength` must be a multiple of the size of `T`.
+    unsafe fn cast_data_slice<T>(&self) -> windows::core::Result<&[T]>;
 }
 
 impl PowerBroadcastSettingExt for POWERBROADCAST_SETTING {
@@ -13,4 +31,98 @@ impl PowerBroadcastSettingExt for POWERBROADCAST_SETTING {
         BOOL::from(self.DataLength == mem::size_of::<T>() as u32).ok_or_e_fail()?;
         Ok(&*ptr::addr_of!(self.Data).cast::<T>())
     }
+
+    unsafe fn cast_data_slice<T>(&self) -> windows::core::Result<&[T]> {
+        let size = mem::size_of::<T>() as u32;
+        BOOL::from(self.DataLength.checked_rem(size) == Some(0)).ok_or_e_fail()?;
+        Ok(slice::from_raw_parts(
+            ptr::addr_of!(self.Data).cast::<T>(),
+            (self.DataLength / size) as _,
+        ))
+    }
+}
+
+/// A registration for `WM_POWERBROADCAST` messages with `PBT_POWERSETTINGCHANGE` for a power setting, made with `RegisterPowerSettingNotification()`. Unregisters on drop.
+///
+/// Translate the messages with `win32_app::window::translate_power_broadcast_msg()` and [`PowerBroadcastSettingExt`].
+///
+/// Activate features `windows_<version>_f_Win32_Foundation` and `windows_<version>_f_Win32_UI_WindowsAndMessaging`.
+#[cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_UI_WindowsAndMessaging"
+))]
+pub struct PowerSettingNotification {
+    h_power_notify: ResGuard<HPOWERNOTIFY>,
+}
+
+#[cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_UI_WindowsAndMessaging"
+))]
+impl PowerSettingNotification {
+    pub fn register(hwnd: HWND, power_setting: &GUID) -> windows::core::Result<Self> {
+        //! Registers the window to receive notifications for the power setting, like `GUID_ACDC_POWER_SOURCE`. The window also receives a notification right away with the current value.
+
+        #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
+        let flags = DEVICE_NOTIFY_WINDOW_HANDLE.0;
+        #[cfg(not(any(feature = "windows_v0_48", feature = "windows_v0_52")))]
+        let flags = DEVICE_NOTIFY_WINDOW_HANDLE;
+
+        Ok(Self {
+            h_power_notify: ResGuard::with_acq_and_unregister_power_setting_notification(
+                || unsafe {
+                    RegisterPowerSettingNotification(HANDLE(hwnd.0), power_setting, flags)
+                },
+            )?,
+        })
+    }
+
+    pub fn h_power_notify(&self) -> HPOWERNOTIFY {
+        *self.h_power_notify
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{PowerBroadcastSettingExt, PowerSettingNotification};
+    use crate::{
+        win32_app::window::{Window, WindowClass},
+        windows, Null,
+    };
+    use windows::{
+        core::GUID,
+        Win32::{Foundation::E_FAIL, System::Power::POWERBROADCAST_SETTING},
+    };
+
+    #[test]
+    fn cast_data_slice() {
+        // `PowerSetting` (16 bytes), `DataLength` and three `u32`s of data.
+        let buffer: [u32; 8] = [0, 0, 0, 0, 12, 1, 2, 3];
+        let setting = unsafe { &*buffer.as_ptr().cast::<POWERBROADCAST_SETTING>() };
+
+        assert_eq!(
+            unsafe { setting.cast_data_slice::<u32>() },
+            Ok(&[1, 2, 3][..])
+        );
+        assert_eq!(
+            unsafe { setting.cast_data_slice::<u64>() },
+            Err(E_FAIL.into())
+        );
+        assert_eq!(unsafe { setting.cast_data::<u32>() }, Err(E_FAIL.into()));
+    }
+
+    #[test]
+    fn power_setting_notification() -> windows::core::Result<()> {
+        const GUID_ACDC_POWER_SOURCE: GUID =
+            GUID::from_u128(0x5d3e9a59_e9d5_4b00_a6bd_ff34ff516548);
+
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        let notification =
+            PowerSettingNotification::register(window.hwnd(), &GUID_ACDC_POWER_SOURCE)?;
+        assert!(!notification.h_power_notify().is_null());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,132 @@
+#![cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Registry"))]
+
+#[cfg(not(feature = "windows_v0_52"))]
+use crate::core::ResultExt;
+use crate::{dual_call_sized, windows, FirstCallExpectation, ResGuard};
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::{ERROR_DATATYPE_MISMATCH, ERROR_MORE_DATA, ERROR_SUCCESS},
+        System::Registry::{
+            RegOpenKeyExW, RegQueryValueExW, HKEY, KEY_READ, REG_DWORD, REG_EXPAND_SZ, REG_SZ,
+            REG_VALUE_TYPE,
+        },
+    },
+};
+
+pub fn open_key(parent: HKEY, sub_key: &str) -> windows::core::Result<ResGuard<HKEY>> {
+    //! Opens the key for reading with [`RegOpenKeyExW()`][1], e.g., below `HKEY_LOCAL_MACHINE`.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regopenkeyexw
+
+    let sub_key = HSTRING::from(sub_key);
+
+    ResGuard::with_mut_acq_and_reg_close_key(|h_key| {
+        let result = unsafe { RegOpenKeyExW(parent, &sub_key, 0, KEY_READ, h_key) };
+        #[cfg(not(feature = "windows_v0_52"))]
+        let result = Result::<(), _>::from_win32_code(result);
+
+        result
+    })
+}
+
+pub fn read_string(h_key: HKEY, value_name: &str) -> windows::core::Result<String> {
+    //! Reads a value of type `REG_SZ` or `REG_EXPAND_SZ`. The latter isn't expanded. Fails with `ERROR_DATATYPE_MISMATCH` for other types.
+    //!
+    //! A missing null terminator is tolerated.
+
+    let (value_type, bytes) = query_value(h_key, value_name)?;
+    if value_type != REG_SZ && value_type != REG_EXPAND_SZ {
+        return Err(ERROR_DATATYPE_MISMATCH.into());
+    }
+
+    let wide_chars = bytes
+        .chunks_exact(2)
+        .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+        .take_while(|&wide_char| wide_char != 0)
+        .collect::<Vec<_>>();
+
+    Ok(String::from_utf16(&wide_chars)?)
+}
+
+pub fn read_u32(h_key: HKEY, value_name: &str) -> windows::core::Result<u32> {
+    //! Reads a value of type `REG_DWORD`. Fails with `ERROR_DATATYPE_MISMATCH` for other types.
+
+    let (value_type, bytes) = query_value(h_key, value_name)?;
+    if value_type != REG_DWORD {
+        return Err(ERROR_DATATYPE_MISMATCH.into());
+    }
+
+    Ok(u32::from_ne_bytes(bytes.try_into().map_err(|_| {
+        windows::core::Error::from(ERROR_DATATYPE_MISMATCH)
+    })?))
+}
+
+fn query_value(h_key: HKEY, value_name: &str) -> windows::core::Result<(REG_VALUE_TYPE, Vec<u8>)> {
+    let value_name = HSTRING::from(value_name);
+    let mut value_type = REG_VALUE_TYPE(0);
+
+    let (bytes, _) = dual_call_sized(
+        // Without a buffer, the first call normally succeeds.
+        FirstCallExpectation::Win32ErrorOneOf(&[ERROR_SUCCESS, ERROR_MORE_DATA]),
+        |buffer_size| query_value_ex(h_key, &value_name, None, None, buffer_size),
+        |buffer, buffer_size| {
+            query_value_ex(
+                h_key,
+                &value_name,
+                Some(&mut value_type),
+                Some(buffer.as_mut_ptr()),
+                buffer_size,
+            )
+        },
+    )?;
+
+    Ok((value_type, bytes))
+}
+
+fn query_value_ex(
+    h_key: HKEY,
+    value_name: &HSTRING,
+    value_type: Option<*mut REG_VALUE_TYPE>,
+    data: Option<*mut u8>,
+    data_size: &mut u32,
+) -> windows::core::Result<()> {
+    let result =
+        unsafe { RegQueryValueExW(h_key, value_name, None, value_type, data, Some(data_size)) };
+    #[cfg(not(feature = "windows_v0_52"))]
+    let result = Result::<(), _>::from_win32_code(result);
+
+    result
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{open_key, read_string, read_u32};
+    use crate::windows;
+    use windows::Win32::{
+        Foundation::{ERROR_DATATYPE_MISMATCH, ERROR_FILE_NOT_FOUND},
+        System::Registry::HKEY_LOCAL_MACHINE,
+    };
+
+    #[test]
+    fn read_values() -> windows::core::Result<()> {
+        let h_key = open_key(
+            HKEY_LOCAL_MACHINE,
+            r"SOFTWARE\Microsoft\Windows NT\CurrentVersion",
+        )?;
+
+        assert!(read_string(*h_key, "ProductName")?.starts_with("Windows"));
+        assert!(read_u32(*h_key, "CurrentMajorVersionNumber")? >= 10);
+
+        assert_eq!(
+            read_u32(*h_key, "ProductName"),
+            Err(ERROR_DATATYPE_MISMATCH.into())
+        );
+        assert_eq!(
+            read_string(*h_key, "NonExistentValue"),
+            Err(ERROR_FILE_NOT_FOUND.into())
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,16 @@
 use crate::{windows, Null};
-use std::ops::Deref;
+use std::{fmt, mem::ManuallyDrop, ops::Deref, ptr};
 
 /// Holds a resource and a free-function (like a non-capturing closure) that is called when the guard is dropped.
 ///
+/// If freeing requires additional data (e.g., the `HWND` for `ReleaseDC()`), a capturing closure can be used instead with the `..._free_closure()` constructors.
+///
 /// Allows to couple resource acquisition and freeing, while treating the guard as the contained resource and ensuring freeing will happen. When writing the code, it's also nice to transfer the documentation into everything that has to happen in one go without having to split it into upper and lower or here- and there-code. In a function, Rust's drop order should ensure that later aquired resources are freed first.
 ///
 /// For functions ending in Windows API function names (differently cased, like `..._destroy_icon()`), you have to activate crate features. First, see the repository's read-me. Then, derive the needed features from the Windows API function and the handle type the instance manages.
-pub struct ResGuard<R: Copy> {
+pub struct ResGuard<R: Copy, F: FnMut(R) = fn(R)> {
     resource: R,
-    free_fn: fn(R),
+    free_fn: F,
 }
 
 impl<R: Copy> ResGuard<R> {
@@ -75,34 +77,181 @@ impl<R: Copy> ResGuard<R> {
             },
         ))
     }
+
+    pub fn three_with_mut_acquisition<A, T, E>(
+        acquire_all: A,
+        free_first: fn(R),
+        free_second: fn(R),
+        free_third: fn(R),
+    ) -> Result<(Self, Self, Self), E>
+    where
+        R: Null,
+        A: FnOnce(&mut R, &mut R, &mut R) -> Result<T, E>,
+    {
+        //! Like [`Self::two_with_mut_acquisition()`], but for three resources.
+
+        let mut first_resource = R::NULL;
+        let mut second_resource = R::NULL;
+        let mut third_resource = R::NULL;
+        acquire_all(
+            &mut first_resource,
+            &mut second_resource,
+            &mut third_resource,
+        )?;
+
+        Ok((
+            Self {
+                resource: first_resource,
+                free_fn: free_first,
+            },
+            Self {
+                resource: second_resource,
+                free_fn: free_second,
+            },
+            Self {
+                resource: third_resource,
+                free_fn: free_third,
+            },
+        ))
+    }
+
+    pub fn free_fn(&self) -> fn(R) {
+        //! Returns the free-function, i.e., the exact function pointer passed to a constructor like [`Self::new()`] or chosen by a typed helper like `with_acq_and_close_handle()`.
+        //!
+        //! Useful when composing guards, e.g., to rewrap a resource after [`Self::map()`].
+
+        self.free_fn
+    }
+
+    pub fn map<B: Copy>(self, convert: fn(R) -> B, free: fn(B)) -> ResGuard<B> {
+        //! Converts the guard into one over another resource type, e.g., when a derived resource shares the lifetime of the original one.
+        //!
+        //! The original free-function is discarded without being called. You are responsible that `free` correctly frees the underlying OS resource of what `convert` returned.
+
+        ResGuard::new(convert(self.release()), free)
+    }
+}
+
+impl<R: Copy, F: FnMut(R)> ResGuard<R, F> {
+    pub fn with_free_closure(resource: R, free: F) -> Self {
+        //! Like [`ResGuard::new()`], but accepting a capturing closure.
+
+        Self {
+            resource,
+            free_fn: free,
+        }
+    }
+
+    pub fn with_acquisition_and_free_closure<A, E>(acquire: A, free: F) -> Result<Self, E>
+    where
+        A: FnOnce() -> Result<R, E>,
+    {
+        //! Like [`ResGuard::with_acquisition()`], but accepting a capturing closure.
+
+        Ok(Self::with_free_closure(acquire()?, free))
+    }
+
+    pub fn with_mut_acquisition_and_free_closure<A, T, E>(acquire: A, free: F) -> Result<Self, E>
+    where
+        R: Null,
+        A: FnOnce(&mut R) -> Result<T, E>,
+    {
+        //! Like [`ResGuard::with_mut_acquisition()`], but accepting a capturing closure.
+
+        let mut resource = R::NULL;
+        acquire(&mut resource)?;
+
+        Ok(Self::with_free_closure(resource, free))
+    }
+
+    pub fn release(self) -> R {
+        //! Returns the resource without freeing it.
+        //!
+        //! For cases in which ownership of the resource is transferred, e.g., to an API that takes over freeing it. If the resource should just never be freed, use [`Self::leak()`] instead.
+
+        let mut this = ManuallyDrop::new(self);
+        // Drop a possibly capturing closure without calling it.
+        unsafe { ptr::drop_in_place(&mut this.free_fn) };
+        this.resource
+    }
+
+    pub fn release_on_success<T, E, X>(self, transfer: X) -> Result<T, E>
+    where
+        X: FnOnce(R) -> Result<T, E>,
+    {
+        //! Passes the resource to the closure, which is meant to transfer ownership of it, and releases the guard like [`Self::release()`] only if the closure succeeds. If it fails, the resource is freed as usual.
+        //!
+        //! For APIs that only take over ownership on success, like `SetClipboardData()` with an `HGLOBAL` from `GlobalAlloc()` with `GMEM_MOVEABLE`, guarded with [`ResGuard::with_acq_and_global_free()`]. This prevents both a leak on failure and a double-free on success.
+
+        let result = transfer(self.resource);
+        if result.is_ok() {
+            self.release();
+        }
+
+        result
+    }
+
+    pub fn leak(self) -> R {
+        //! Returns the resource, deliberately never freeing it.
+        //!
+        //! For resources that are to live as long as the process, like a module loaded with `LoadLibraryW()`, while still being able to use the acquisition functions. Technically the same as [`Self::release()`], but communicating a different intent.
+
+        self.release()
+    }
 }
 
 macro_rules! impl_with_acq_and_free_fn {
-    ($type:ty, $with_res:ident, $with_acq:ident, $with_acq_mut:ident, $free_fn:expr) => {
+    // Variant with custom constant name for types with multiple free-functions.
+    ($type:ty, $free_fn_const:ident, $with_res:ident, $with_acq:ident, $with_acq_mut:ident, $free_fn:expr) => {
         impl ResGuard<$type> {
-            const FREE_FN: fn($type) = $free_fn;
+            const $free_fn_const: fn($type) = $free_fn;
 
             pub fn $with_res(resource: $type) -> Self {
-                Self::new(resource, Self::FREE_FN)
+                Self::new(resource, Self::$free_fn_const)
             }
 
             pub fn $with_acq<A, E>(acquire: A) -> Result<Self, E>
             where
                 A: FnOnce() -> Result<$type, E>,
             {
-                Self::with_acquisition(acquire, Self::FREE_FN)
+                Self::with_acquisition(acquire, Self::$free_fn_const)
             }
 
             pub fn $with_acq_mut<A, T, E>(acquire: A) -> Result<Self, E>
             where
                 A: FnOnce(&mut $type) -> Result<T, E>,
             {
-                Self::with_mut_acquisition(acquire, Self::FREE_FN)
+                Self::with_mut_acquisition(acquire, Self::$free_fn_const)
             }
         }
     };
+    ($type:ty, $with_res:ident, $with_acq:ident, $with_acq_mut:ident, $free_fn:expr) => {
+        impl_with_acq_and_free_fn!(
+            $type,
+            FREE_FN,
+            $with_res,
+            $with_acq,
+            $with_acq_mut,
+            $free_fn
+        );
+    };
 }
 
+#[cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_UI_WindowsAndMessaging"
+))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::UI::WindowsAndMessaging::HACCEL,
+    with_res_and_destroy_accelerator_table,
+    with_acq_and_destroy_accelerator_table,
+    with_mut_acq_and_destroy_accelerator_table,
+    |h_accel| {
+        let _ =
+            unsafe { windows::Win32::UI::WindowsAndMessaging::DestroyAcceleratorTable(h_accel) };
+    }
+);
+
 #[cfg(all(feature = "f_Win32_Foundation"))]
 impl_with_acq_and_free_fn!(
     windows::Win32::Foundation::HANDLE,
@@ -160,6 +309,29 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+//. For `WM_DROPFILES`. Calls to `DragQueryFileW()` etc. must be made before the guard is dropped.
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_UI_Shell"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::UI::Shell::HDROP,
+    with_res_and_drag_finish,
+    with_acq_and_drag_finish,
+    with_mut_acq_and_drag_finish,
+    |h_drop| {
+        unsafe { windows::Win32::UI::Shell::DragFinish(h_drop) };
+    }
+);
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::Graphics::Gdi::HENHMETAFILE,
+    with_res_and_delete_enh_meta_file,
+    with_acq_and_delete_enh_meta_file,
+    with_mut_acq_and_delete_enh_meta_file,
+    |h_enh_meta_file| {
+        unsafe { windows::Win32::Graphics::Gdi::DeleteEnhMetaFile(h_enh_meta_file) };
+    }
+);
+
 #[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
 impl_with_acq_and_free_fn!(
     windows::Win32::Graphics::Gdi::HFONT,
@@ -206,6 +378,20 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+#[cfg(all(
+    feature = "f_Win32_Foundation",
+    feature = "f_Win32_UI_WindowsAndMessaging"
+))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::UI::WindowsAndMessaging::HHOOK,
+    with_res_and_unhook_windows_hook_ex,
+    with_acq_and_unhook_windows_hook_ex,
+    with_mut_acq_and_unhook_windows_hook_ex,
+    |h_hook| {
+        let _ = unsafe { windows::Win32::UI::WindowsAndMessaging::UnhookWindowsHookEx(h_hook) };
+    }
+);
+
 #[cfg(all(
     feature = "f_Win32_Foundation",
     feature = "f_Win32_UI_WindowsAndMessaging"
@@ -220,6 +406,17 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Registry"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::System::Registry::HKEY,
+    with_res_and_reg_close_key,
+    with_acq_and_reg_close_key,
+    with_mut_acq_and_reg_close_key,
+    |h_key| {
+        let _ = unsafe { windows::Win32::System::Registry::RegCloseKey(h_key) };
+    }
+);
+
 #[cfg(feature = "windows_v0_48")]
 #[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
 impl_with_acq_and_free_fn!(
@@ -258,6 +455,17 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::Graphics::Gdi::HMETAFILE,
+    with_res_and_delete_meta_file,
+    with_acq_and_delete_meta_file,
+    with_mut_acq_and_delete_meta_file,
+    |h_meta_file| {
+        unsafe { windows::Win32::Graphics::Gdi::DeleteMetaFile(h_meta_file) };
+    }
+);
+
 #[cfg(feature = "windows_v0_48")]
 #[cfg(all(
     feature = "f_Win32_Foundation",
@@ -331,6 +539,28 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_UI_Accessibility"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    with_res_and_unhook_win_event,
+    with_acq_and_unhook_win_event,
+    with_mut_acq_and_unhook_win_event,
+    |h_win_event_hook| {
+        let _ = unsafe { windows::Win32::UI::Accessibility::UnhookWinEvent(h_win_event_hook) };
+    }
+);
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Services"))]
+impl_with_acq_and_free_fn!(
+    windows::Win32::System::Services::SC_HANDLE,
+    with_res_and_close_service_handle,
+    with_acq_and_close_service_handle,
+    with_mut_acq_and_close_service_handle,
+    |sc_handle| {
+        let _ = unsafe { windows::Win32::System::Services::CloseServiceHandle(sc_handle) };
+    }
+);
+
 #[cfg(feature = "windows_v0_48")]
 #[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
 impl_with_acq_and_free_fn!(
@@ -364,6 +594,19 @@ impl_with_acq_and_free_fn!(
     }
 );
 
+//. Useful for functions like `SHGetKnownFolderPath()`, which allocate for you and are documented to require a call to `CoTaskMemFree()`.
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Com"))]
+impl_with_acq_and_free_fn!(
+    windows::core::PWSTR,
+    CO_TASK_MEM_FREE_FN,
+    with_res_and_co_task_mem_free,
+    with_acq_and_co_task_mem_free,
+    with_mut_acq_and_co_task_mem_free,
+    |pwstr| {
+        unsafe { windows::Win32::System::Com::CoTaskMemFree(Some(pwstr.0.cast_const().cast())) };
+    }
+);
+
 #[cfg(feature = "f_Win32_Foundation")]
 impl ResGuard<windows::Win32::Foundation::HANDLE> {
     // (`FREE_FN` was already defined in previous impl with this type parameter.)
@@ -381,9 +624,24 @@ impl ResGuard<windows::Win32::Foundation::HANDLE> {
 
         Self::two_with_mut_acquisition(acquire_both, Self::FREE_FN, Self::FREE_FN)
     }
+
+    pub fn three_with_mut_acq_and_close_handle<A, T, E>(
+        acquire_all: A,
+    ) -> Result<(Self, Self, Self), E>
+    where
+        A: FnOnce(
+            &mut windows::Win32::Foundation::HANDLE,
+            &mut windows::Win32::Foundation::HANDLE,
+            &mut windows::Win32::Foundation::HANDLE,
+        ) -> Result<T, E>,
+    {
+        //! Like [`Self::two_with_mut_acq_and_close_handle()`], but for three resources.
+
+        Self::three_with_mut_acquisition(acquire_all, Self::FREE_FN, Self::FREE_FN, Self::FREE_FN)
+    }
 }
 
-impl<R: Copy> Deref for ResGuard<R> {
+impl<R: Copy, F: FnMut(R)> Deref for ResGuard<R, F> {
     type Target = R;
 
     fn deref(&self) -> &Self::Target {
@@ -391,30 +649,221 @@ impl<R: Copy> Deref for ResGuard<R> {
     }
 }
 
-impl<R: Copy> Drop for ResGuard<R> {
+impl<R: Copy + fmt::Debug, F: FnMut(R)> fmt::Debug for ResGuard<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // (The free-function has no meaningful `Debug` output.)
+        f.debug_struct("ResGuard")
+            .field("resource", &self.resource)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Copy, F: FnMut(R)> Drop for ResGuard<R, F> {
     fn drop(&mut self) {
         (self.free_fn)(self.resource);
     }
 }
 
+/// Holds the device context from `BeginPaint()` and calls `EndPaint()` with the same `PAINTSTRUCT` when dropped.
+///
+/// Can be treated as the contained `HDC`.
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+pub struct PaintGuard {
+    hwnd: windows::Win32::Foundation::HWND,
+    paint_struct: windows::Win32::Graphics::Gdi::PAINTSTRUCT,
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+impl PaintGuard {
+    pub fn begin(hwnd: windows::Win32::Foundation::HWND) -> windows::core::Result<Self> {
+        //! Calls [`BeginPaint()`][1]. To be used when handling `WM_PAINT`.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-beginpaint
+
+        use crate::core::CheckNullError;
+
+        let mut paint_struct = windows::Win32::Graphics::Gdi::PAINTSTRUCT::default();
+        unsafe { windows::Win32::Graphics::Gdi::BeginPaint(hwnd, &mut paint_struct) }
+            .nonnull_or_e_handle()?;
+
+        Ok(Self { hwnd, paint_struct })
+    }
+
+    pub fn paint_struct(&self) -> &windows::Win32::Graphics::Gdi::PAINTSTRUCT {
+        &self.paint_struct
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+impl Deref for PaintGuard {
+    type Target = windows::Win32::Graphics::Gdi::HDC;
+
+    fn deref(&self) -> &Self::Target {
+        &self.paint_struct.hdc
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_Graphics_Gdi"))]
+impl Drop for PaintGuard {
+    fn drop(&mut self) {
+        let _ = unsafe { windows::Win32::Graphics::Gdi::EndPaint(self.hwnd, &self.paint_struct) };
+    }
+}
+
+/// Holds the pointer from `GlobalLock()` and calls `GlobalUnlock()` when dropped.
+///
+/// Can be treated as the contained pointer. This is about the lock scope, while [`ResGuard::with_res_and_global_free()`] etc. are about freeing the `HGLOBAL`.
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+pub struct GlobalLockGuard {
+    h_global: windows::Win32::Foundation::HGLOBAL,
+    ptr: *mut std::ffi::c_void,
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl GlobalLockGuard {
+    pub fn lock(h_global: windows::Win32::Foundation::HGLOBAL) -> windows::core::Result<Self> {
+        //! Calls [`GlobalLock()`][1].
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globallock
+
+        use crate::core::ResultExt;
+
+        let ptr = Result::from_checked_or_win32(
+            unsafe { windows::Win32::System::Memory::GlobalLock(h_global) },
+            |ptr| !ptr.is_null(),
+        )?;
+
+        Ok(Self { h_global, ptr })
+    }
+
+    pub fn h_global(&self) -> windows::Win32::Foundation::HGLOBAL {
+        self.h_global
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl Deref for GlobalLockGuard {
+    type Target = *mut std::ffi::c_void;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ptr
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl Drop for GlobalLockGuard {
+    fn drop(&mut self) {
+        let _ = unsafe { windows::Win32::System::Memory::GlobalUnlock(self.h_global) };
+    }
+}
+
+/// Holds the base address of a view of a file mapping from `MapViewOfFile()` and calls `UnmapViewOfFile()` when dropped.
+///
+/// Can be treated as the contained pointer. The mapping `HANDLE` itself can be managed with [`ResGuard::with_acq_and_close_handle()`] etc. It may be closed before the view is unmapped, since the view keeps the mapping object alive.
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+pub struct MappedView {
+    ptr: *mut std::ffi::c_void,
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl MappedView {
+    pub fn map(
+        h_file_mapping: windows::Win32::Foundation::HANDLE,
+        desired_access: windows::Win32::System::Memory::FILE_MAP,
+        offset: u64,
+        num_bytes: usize,
+    ) -> windows::core::Result<Self> {
+        //! Calls [`MapViewOfFile()`][1]. The offset must be a multiple of the allocation granularity. With `num_bytes` being 0, the view extends to the end of the mapping.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-mapviewoffile
+
+        use crate::core::ResultExt;
+
+        let address = unsafe {
+            windows::Win32::System::Memory::MapViewOfFile(
+                h_file_mapping,
+                desired_access,
+                (offset >> 32) as _,
+                offset as _,
+                num_bytes,
+            )
+        };
+        #[cfg(feature = "windows_v0_48")]
+        let ptr = address?.0 as *mut std::ffi::c_void;
+        #[cfg(not(feature = "windows_v0_48"))]
+        let ptr = address.Value;
+
+        let ptr = Result::from_checked_or_win32(ptr, |ptr| !ptr.is_null())?;
+
+        Ok(Self { ptr })
+    }
+
+    #[cfg(not(feature = "windows_v0_48"))]
+    pub fn address(&self) -> windows::Win32::System::Memory::MEMORY_MAPPED_VIEW_ADDRESS {
+        windows::Win32::System::Memory::MEMORY_MAPPED_VIEW_ADDRESS { Value: self.ptr }
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl Deref for MappedView {
+    type Target = *mut std::ffi::c_void;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ptr
+    }
+}
+
+#[cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_System_Memory"))]
+impl Drop for MappedView {
+    fn drop(&mut self) {
+        #[cfg(feature = "windows_v0_48")]
+        let address = windows::Win32::System::Memory::MEMORYMAPPEDVIEW_HANDLE(self.ptr as _);
+        #[cfg(not(feature = "windows_v0_48"))]
+        let address = self.address();
+
+        let _ = unsafe { windows::Win32::System::Memory::UnmapViewOfFile(address) };
+    }
+}
+
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
-    use super::ResGuard;
+    use super::{GlobalLockGuard, MappedView, ResGuard};
     use crate::{
         core::{CheckNullError, CheckNumberError},
+        shell::make_test_h_drop,
         windows, Null,
     };
-    use std::{mem, ptr};
+    use regex::Regex;
+    use std::{cell::Cell, mem, ptr};
     use windows::{
-        core::PCWSTR,
+        core::{s, w, PCWSTR, PWSTR},
         Win32::{
-            Foundation::{CloseHandle, COLORREF},
-            Graphics::Gdi::{CreateSolidBrush, GetObjectW, HBRUSH, LOGBRUSH},
+            Foundation::{CloseHandle, COLORREF, HANDLE, HMODULE, HWND, INVALID_HANDLE_VALUE},
+            Graphics::Gdi::{
+                CloseEnhMetaFile, CloseMetaFile, CreateEnhMetaFileW, CreateMetaFileW,
+                CreateSolidBrush, GetDC, GetDeviceCaps, GetEnhMetaFileHeader, GetObjectW,
+                ReleaseDC, HBRUSH, HDC, LOGBRUSH, LOGPIXELSX,
+            },
             Storage::FileSystem::{ReadFile, WriteFile},
             System::{
+                LibraryLoader::{GetProcAddress, LoadLibraryW},
+                Memory::{
+                    CreateFileMappingW, GlobalAlloc, FILE_MAP_ALL_ACCESS, FILE_MAP_READ,
+                    GMEM_MOVEABLE, PAGE_READWRITE,
+                },
                 Pipes::CreatePipe,
+                Registry::{RegOpenKeyExW, RegQueryInfoKeyW, HKEY_CURRENT_USER, KEY_READ},
+                Services::{
+                    OpenSCManagerW, OpenServiceW, QueryServiceStatus, SC_MANAGER_CONNECT,
+                    SERVICE_QUERY_STATUS, SERVICE_RUNNING, SERVICE_STATUS,
+                },
                 Threading::{CreateEventW, SetEvent},
             },
+            UI::{
+                Accessibility::{SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK},
+                Shell::{DragQueryFileW, FOLDERID_Windows, SHGetKnownFolderPath, KF_FLAG_DEFAULT},
+                WindowsAndMessaging::{EVENT_MIN, WINEVENT_OUTOFCONTEXT},
+            },
         },
     };
 
@@ -429,6 +878,71 @@ mod tests {
         assert_eq!(unsafe { SetEvent(*event_handle) }, Ok(()));
     }
 
+    #[test]
+    fn with_acquisition_and_free_closure() -> windows::core::Result<()> {
+        let hwnd = HWND::NULL; // Screen.
+
+        let h_dc = ResGuard::with_acquisition_and_free_closure(
+            || unsafe { GetDC(hwnd) }.nonnull_or_e_handle(),
+            move |h_dc| {
+                unsafe { ReleaseDC(hwnd, h_dc) };
+            },
+        )?;
+
+        assert!(unsafe { GetDeviceCaps(*h_dc, LOGPIXELSX) } > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug() {
+        let event_handle = ResGuard::with_acq_and_close_handle(|| unsafe {
+            CreateEventW(None, true, false, PCWSTR::NULL)
+        })
+        .expect("should be able to create event handle");
+
+        assert!(format!("{event_handle:?}").contains(&format!("{:?}", *event_handle)));
+    }
+
+    #[test]
+    fn release() {
+        let event_handle = ResGuard::with_acq_and_close_handle(|| unsafe {
+            CreateEventW(None, true, false, PCWSTR::NULL)
+        })
+        .expect("should be able to create event handle")
+        .release();
+
+        assert_eq!(unsafe { SetEvent(event_handle) }, Ok(()));
+        assert_eq!(unsafe { CloseHandle(event_handle) }, Ok(()));
+    }
+
+    #[test]
+    fn release_on_success() {
+        let num_frees = Cell::new(0);
+        let free = |_| num_frees.set(num_frees.get() + 1);
+
+        let result =
+            ResGuard::with_free_closure(1, free).release_on_success(|n| Ok::<_, ()>(n + 1));
+        assert_eq!(result, Ok(2));
+        assert_eq!(num_frees.get(), 0);
+
+        let result =
+            ResGuard::with_free_closure(1, free).release_on_success(|_| Err::<(), _>("no"));
+        assert_eq!(result, Err("no"));
+        assert_eq!(num_frees.get(), 1);
+    }
+
+    #[test]
+    fn leak() -> windows::core::Result<()> {
+        let h_module =
+            ResGuard::with_acq_and_free_library(|| unsafe { LoadLibraryW(w!("shell32.dll")) })?
+                .leak();
+
+        assert!(unsafe { GetProcAddress(h_module, s!("ShellExecuteW")) }.is_some());
+
+        Ok(())
+    }
+
     #[test]
     fn with_acq_and_close_handle() {
         let event_handle = ResGuard::with_acq_and_close_handle(|| unsafe {
@@ -469,6 +983,23 @@ mod tests {
         assert_eq!(buffer, bytes);
     }
 
+    #[test]
+    fn three_with_mut_acq_and_close_handle() -> windows::core::Result<()> {
+        let (first_handle, second_handle, third_handle) =
+            ResGuard::three_with_mut_acq_and_close_handle(|first, second, third| {
+                for handle in [first, second, third] {
+                    *handle = unsafe { CreateEventW(None, true, false, PCWSTR::NULL) }?;
+                }
+                windows::core::Result::Ok(())
+            })?;
+
+        for handle in [first_handle, second_handle, third_handle] {
+            assert_eq!(unsafe { SetEvent(*handle) }, Ok(()));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn with_acq_and_delete_object() -> windows::core::Result<()> {
         //! Tests handle type conversion: `HBRUSH` to `HGDIOBJ`.
@@ -493,4 +1024,185 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn with_acq_and_delete_meta_files() -> windows::core::Result<()> {
+        let h_enh_meta_file = ResGuard::with_acq_and_delete_enh_meta_file(|| {
+            let h_dc = unsafe { CreateEnhMetaFileW(HDC::NULL, PCWSTR::NULL, None, PCWSTR::NULL) }
+                .nonnull_or_e_handle()?;
+            unsafe { CloseEnhMetaFile(h_dc) }.nonnull_or_e_handle()
+        })?;
+
+        assert_ne!(
+            unsafe { GetEnhMetaFileHeader(*h_enh_meta_file, 0, None) },
+            0
+        );
+
+        let _h_meta_file = ResGuard::with_acq_and_delete_meta_file(|| {
+            let h_dc = unsafe { CreateMetaFileW(PCWSTR::NULL) }.nonnull_or_e_handle()?;
+            unsafe { CloseMetaFile(h_dc) }.nonnull_or_e_handle()
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_mut_acq_and_reg_close_key() -> windows::core::Result<()> {
+        let h_key = ResGuard::with_mut_acq_and_reg_close_key(|h_key| {
+            unsafe { RegOpenKeyExW(HKEY_CURRENT_USER, w!("Software"), 0, KEY_READ, h_key) }.ok()
+        })?;
+
+        let mut num_sub_keys = 0;
+        unsafe {
+            RegQueryInfoKeyW(
+                *h_key,
+                PWSTR::NULL,
+                None,
+                None,
+                Some(&mut num_sub_keys),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        }
+        .ok()?;
+
+        assert!(num_sub_keys >= 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_acq_and_co_task_mem_free() -> windows::core::Result<()> {
+        let path = ResGuard::<PWSTR>::with_acq_and_co_task_mem_free(|| unsafe {
+            SHGetKnownFolderPath(&FOLDERID_Windows, KF_FLAG_DEFAULT, HANDLE::NULL)
+        })?;
+
+        assert!(Regex::new(r"(?i)^[A-Z]:\\Windows$")
+            .unwrap()
+            .is_match(&unsafe { path.to_string() }?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_acq_and_unhook_win_event() -> windows::core::Result<()> {
+        unsafe extern "system" fn on_win_event(
+            _h_win_event_hook: HWINEVENTHOOK,
+            _event: u32,
+            _hwnd: HWND,
+            _object_id: i32,
+            _child_id: i32,
+            _event_thread_id: u32,
+            _event_time: u32,
+        ) {
+        }
+
+        let h_win_event_hook = ResGuard::with_acq_and_unhook_win_event(|| {
+            unsafe {
+                SetWinEventHook(
+                    EVENT_MIN,
+                    EVENT_MIN,
+                    HMODULE::NULL,
+                    Some(on_win_event),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                )
+            }
+            .nonnull_or_e_handle()
+        })?;
+
+        let raw_h_win_event_hook = *h_win_event_hook;
+        drop(h_win_event_hook);
+
+        // Already unhooked.
+        assert!(!unsafe { UnhookWinEvent(raw_h_win_event_hook) }.as_bool());
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_acq_and_close_service_handle() -> windows::core::Result<()> {
+        let sc_manager_handle = ResGuard::with_acq_and_close_service_handle(|| unsafe {
+            OpenSCManagerW(PCWSTR::NULL, PCWSTR::NULL, SC_MANAGER_CONNECT)
+        })?;
+        let service_handle = ResGuard::with_acq_and_close_service_handle(|| unsafe {
+            OpenServiceW(*sc_manager_handle, w!("EventLog"), SERVICE_QUERY_STATUS)
+        })?;
+
+        let mut service_status = SERVICE_STATUS::default();
+        unsafe { QueryServiceStatus(*service_handle, &mut service_status) }?;
+
+        assert_eq!(service_status.dwCurrentState, SERVICE_RUNNING);
+
+        Ok(())
+    }
+
+    #[test]
+    fn global_lock_guard() -> windows::core::Result<()> {
+        const BYTES: [u8; 3] = [12, 34, 56];
+
+        let h_global = ResGuard::with_acq_and_global_free(|| unsafe {
+            GlobalAlloc(GMEM_MOVEABLE, BYTES.len())
+        })?;
+
+        {
+            let ptr = GlobalLockGuard::lock(*h_global)?;
+            unsafe { ptr::copy_nonoverlapping(BYTES.as_ptr(), ptr.cast(), BYTES.len()) };
+        }
+
+        let ptr = GlobalLockGuard::lock(*h_global)?;
+        assert_eq!(
+            unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), BYTES.len()) },
+            BYTES
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mapped_view() -> windows::core::Result<()> {
+        const BYTES: [u8; 3] = [12, 34, 56];
+
+        // Backed by the paging file.
+        let h_file_mapping = ResGuard::with_acq_and_close_handle(|| unsafe {
+            CreateFileMappingW(
+                INVALID_HANDLE_VALUE,
+                None,
+                PAGE_READWRITE,
+                0,
+                4096,
+                PCWSTR::NULL,
+            )
+        })?;
+
+        let writable_view = MappedView::map(*h_file_mapping, FILE_MAP_ALL_ACCESS, 0, 0)?;
+        let readable_view = MappedView::map(*h_file_mapping, FILE_MAP_READ, 0, BYTES.len())?;
+        assert_ne!(*writable_view, *readable_view);
+        drop(h_file_mapping);
+
+        unsafe { ptr::copy_nonoverlapping(BYTES.as_ptr(), writable_view.cast(), BYTES.len()) };
+        drop(writable_view);
+
+        assert_eq!(
+            unsafe { std::slice::from_raw_parts(readable_view.cast::<u8>(), BYTES.len()) },
+            BYTES
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_res_and_drag_finish() -> windows::core::Result<()> {
+        // Simulate a drop of two files, like the system does on `WM_DROPFILES`.
+        let h_drop = make_test_h_drop(&[r"C:\a.txt", r"C:\b.txt"])?;
+        assert_eq!(unsafe { DragQueryFileW(*h_drop, u32::MAX, None) }, 2);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+#![cfg(all(feature = "f_Win32_Foundation", feature = "f_Win32_UI_Shell"))]
+
+use crate::{core::CheckNumberError, dual_call, windows, FirstCallExpectation};
+use std::path::PathBuf;
+use windows::Win32::UI::Shell::{DragQueryFileW, HDROP};
+
+pub fn dropped_files(h_drop: HDROP) -> windows::core::Result<Vec<PathBuf>> {
+    //! Returns the paths of the files dropped on a window, as delivered with `WM_DROPFILES`. See [`DragQueryFileW()`][1].
+    //!
+    //! The `HDROP` can be managed with [`crate::ResGuard::with_res_and_drag_finish()`].
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragqueryfilew
+
+    let num_files = unsafe { DragQueryFileW(h_drop, u32::MAX, None) };
+
+    (0..num_files)
+        .map(|index| {
+            let mut buffer = Vec::new();
+            let mut len = 0;
+
+            dual_call(FirstCallExpectation::Ok, |getting_buffer_size| {
+                len = unsafe {
+                    DragQueryFileW(
+                        h_drop,
+                        index,
+                        (!getting_buffer_size).then(|| {
+                            buffer.resize(len as usize + 1 /*null-termination*/, 0);
+                            buffer.as_mut_slice()
+                        }),
+                    )
+                }
+                .nonzero_or_e_fail()?;
+
+                Ok(())
+            })?;
+
+            Ok(PathBuf::from(String::from_utf16(&buffer[..len as _])?))
+        })
+        .collect()
+}
+
+/// Makes an `HDROP` like the system does for `WM_DROPFILES`: a `DROPFILES` header, followed by a double-null-terminated list of paths.
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+pub(crate) fn make_test_h_drop(paths: &[&str]) -> windows::core::Result<crate::ResGuard<HDROP>> {
+    use crate::{GlobalLockGuard, ResGuard};
+    use std::{mem, ptr};
+    use windows::Win32::{
+        System::Memory::{GlobalAlloc, GMEM_MOVEABLE},
+        UI::Shell::DROPFILES,
+    };
+
+    let mut wide_paths = paths
+        .iter()
+        .flat_map(|path| path.encode_utf16().chain([0]))
+        .collect::<Vec<_>>();
+    wide_paths.push(0);
+    let header_size = mem::size_of::<DROPFILES>();
+
+    let h_global = unsafe {
+        GlobalAlloc(
+            GMEM_MOVEABLE,
+            header_size + wide_paths.len() * mem::size_of::<u16>(),
+        )
+    }?;
+
+    {
+        let ptr = GlobalLockGuard::lock(h_global)?;
+        unsafe {
+            *ptr.cast::<DROPFILES>() = DROPFILES {
+                pFiles: header_size as _,
+                fWide: true.into(),
+                ..Default::default()
+            };
+            ptr::copy_nonoverlapping(
+                wide_paths.as_ptr(),
+                ptr.cast::<u8>().add(header_size).cast(),
+                wide_paths.len(),
+            );
+        }
+    }
+
+    // Frees the memory.
+    Ok(ResGuard::with_res_and_drag_finish(HDROP(h_global.0)))
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{dropped_files, make_test_h_drop};
+    use crate::windows;
+    use std::path::PathBuf;
+
+    #[test]
+    fn dropped_files_from_h_drop() -> windows::core::Result<()> {
+        let h_drop = make_test_h_drop(&[r"C:\a.txt", r"C:\Folder with spaces\ä.png"])?;
+        assert_eq!(
+            dropped_files(*h_drop)?,
+            [
+                PathBuf::from(r"C:\a.txt"),
+                PathBuf::from(r"C:\Folder with spaces\ä.png")
+            ]
+        );
+
+        let h_drop = make_test_h_drop(&[])?;
+        assert_eq!(dropped_files(*h_drop)?, Vec::<PathBuf>::new());
+
+        Ok(())
+    }
+}
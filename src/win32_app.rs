@@ -14,8 +14,14 @@ pub mod tray_icon;
 pub mod window;
 
 mod app;
+#[cfg(feature = "f_Win32_Security_Cryptography")]
+mod guid;
+mod menu;
 
 pub use app::*;
+#[cfg(feature = "f_Win32_Security_Cryptography")]
+pub use guid::*;
+pub use menu::*;
 
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
@@ -23,23 +29,21 @@ mod tests {
         error::{try_or_quit_now, try_or_set_app_error, try_then_favor_app_error},
         tray_icon::SimplifiedTrayIconMsg,
         window::translate_timer_msg,
-        AppLike, InvisibleWindowAppHelper,
+        AppLike, InvisibleWindowAppHelper, VisibleWindowAppHelper,
     };
     use crate::{
         cell::ReentrantRefCell,
         core::CheckNumberError,
-        win32_app::{
-            msg_loop,
-            tray_icon::{BalloonIcon, TrayIcon},
-        },
+        win32_app::tray_icon::{BalloonIcon, BalloonOptions, TrayIcon},
         windows, Null, ResGuard,
     };
     use anyhow::anyhow;
     use std::rc::Rc;
     use windows::Win32::{
-        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, SIZE, WPARAM},
         UI::WindowsAndMessaging::{
-            DestroyWindow, KillTimer, LoadIconW, PostQuitMessage, SetTimer, HICON, IDI_APPLICATION,
+            DestroyWindow, GetClassLongW, GetWindowTextLengthW, IsWindowVisible, KillTimer,
+            LoadIconW, PostQuitMessage, SetTimer, CS_DBLCLKS, GCL_STYLE, HICON, IDI_APPLICATION,
             WM_APP, WM_CREATE, WM_DESTROY, WM_TIMER,
         },
     };
@@ -48,8 +52,8 @@ mod tests {
     #[test]
     fn tray_icon_app() -> anyhow::Result<()> {
         try_then_favor_app_error(|| -> anyhow::Result<()> {
-            let (_app_helper, _app) = App::new()?;
-            msg_loop::run()?;
+            let (app_helper, app) = App::new()?;
+            app_helper.run_msg_loop(&app)?;
 
             Ok(())
         })
@@ -128,14 +132,13 @@ mod tests {
                         try_or_quit_now(|| -> windows::core::Result<()> {
                             unsafe { KillTimer(hwnd, Self::TIMER_ID)? };
 
-                            self.tray_icon.show_balloon(
-                                BalloonIcon::User,
-                                Some("Title"),
-                                "This is the notification message.",
-                                false,
-                                true,
-                                true,
-                            )?;
+                            self.tray_icon.show_balloon_with(BalloonOptions {
+                                icon: BalloonIcon::User,
+                                title: Some("Title".into()),
+                                text: "This is the notification message.".into(),
+                                override_quiet_time: true,
+                                ..Default::default()
+                            })?;
 
                             self.tray_icon.set_tooltip(Some("Click the icon to exit"))?;
 
@@ -168,4 +171,117 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn customized_invisible_window_app() -> windows::core::Result<()> {
+        let (_app_helper, app) = CustomizedApp::new()?;
+        let hwnd = app.borrow().as_ref().map(|app| app.hwnd).unwrap();
+
+        assert_eq!(unsafe { GetClassLongW(hwnd, GCL_STYLE) }, CS_DBLCLKS.0);
+        assert_eq!(unsafe { GetWindowTextLengthW(hwnd) }, "Helper".len() as _);
+        assert!(!unsafe { IsWindowVisible(hwnd) }.as_bool());
+
+        Ok(())
+    }
+
+    struct CustomizedApp {
+        hwnd: HWND,
+    }
+
+    impl<'a> AppLike<InvisibleWindowAppHelper<'a>> for CustomizedApp {
+        fn new() -> windows::core::Result<(
+            InvisibleWindowAppHelper<'a>,
+            Rc<ReentrantRefCell<Option<Self>>>,
+        )> {
+            Ok(unsafe {
+                InvisibleWindowAppHelper::make_app_with(
+                    |class| class.style(CS_DBLCLKS),
+                    |window| window.text("Helper"),
+                )?
+            })
+        }
+
+        fn startup_wnd_proc(
+            hwnd: HWND,
+            msg_id: u32,
+            _wparam: WPARAM,
+            _lparam: LPARAM,
+        ) -> (Option<Self>, Option<LRESULT>) {
+            if msg_id == WM_CREATE {
+                (Some(Self { hwnd }), Some(LRESULT(0)))
+            } else {
+                (None, None)
+            }
+        }
+
+        fn wnd_proc(
+            &mut self,
+            _hwnd: HWND,
+            _msg_id: u32,
+            _wparam: WPARAM,
+            _lparam: LPARAM,
+        ) -> Option<LRESULT> {
+            None
+        }
+    }
+
+    #[ignore]
+    #[test]
+    fn visible_window_app() -> windows::core::Result<()> {
+        let (app_helper, app) = VisibleApp::new()?;
+        app_helper.run_msg_loop(&app)?;
+        assert!(app.borrow().as_ref().is_some_and(|app| app.quit));
+
+        Ok(())
+    }
+
+    struct VisibleApp {
+        quit: bool,
+    }
+
+    impl<'a> AppLike<VisibleWindowAppHelper<'a>> for VisibleApp {
+        fn new() -> windows::core::Result<(
+            VisibleWindowAppHelper<'a>,
+            Rc<ReentrantRefCell<Option<Self>>>,
+        )> {
+            Ok(unsafe {
+                VisibleWindowAppHelper::make_app(
+                    "Close the window to exit",
+                    SIZE { cx: 400, cy: 300 },
+                )?
+            })
+        }
+
+        fn startup_wnd_proc(
+            _hwnd: HWND,
+            msg_id: u32,
+            _wparam: WPARAM,
+            _lparam: LPARAM,
+        ) -> (Option<Self>, Option<LRESULT>) {
+            if msg_id == WM_CREATE {
+                (Some(Self { quit: false }), Some(LRESULT(0)))
+            } else {
+                (None, None)
+            }
+        }
+
+        fn wnd_proc(
+            &mut self,
+            _hwnd: HWND,
+            msg_id: u32,
+            _wparam: WPARAM,
+            _lparam: LPARAM,
+        ) -> Option<LRESULT> {
+            if msg_id == WM_DESTROY {
+                unsafe { PostQuitMessage(0) };
+                Some(LRESULT(0))
+            } else {
+                None
+            }
+        }
+
+        fn on_quit(&mut self) {
+            self.quit = true;
+        }
+    }
 }
@@ -1,7 +1,13 @@
-use super::window::{Window, WindowClass};
-use crate::{cell::ReentrantRefCell, windows};
-use std::rc::Rc;
-use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+use super::{
+    msg_loop,
+    window::{Window, WindowBuilder, WindowClass, WindowClassBuilder, WndProc},
+};
+use crate::{cell::ReentrantRefCell, windows, Zeroed};
+use std::rc::{Rc, Weak};
+use windows::Win32::{
+    Foundation::{HWND, LPARAM, LRESULT, POINT, SIZE, WPARAM},
+    UI::WindowsAndMessaging::{WS_OVERLAPPEDWINDOW, WS_VISIBLE},
+};
 
 pub struct InvisibleWindowAppHelper<'a> {
     _window: Window,
@@ -20,25 +26,35 @@ impl<'a> InvisibleWindowAppHelper<'a> {
         //! # Safety
         //! See [`AppLike::wnd_proc()`].
 
+        Self::make_app_with(|class| class, |window| window)
+    }
+
+    pub unsafe fn make_app_with<App, C, W>(
+        configure_class: C,
+        configure_window: W,
+    ) -> windows::core::Result<(Self, Rc<ReentrantRefCell<Option<App>>>)>
+    where
+        App: AppLike<Self> + 'a,
+        C: FnOnce(WindowClassBuilder) -> WindowClassBuilder,
+        W: for<'c> FnOnce(WindowBuilder<'c, 'a>) -> WindowBuilder<'c, 'a>,
+    {
+        //! Like [`Self::make_app()`], but lets you customize the helper window, e.g., to set a class icon or to make the window visible.
+        //!
+        //! `configure_class` receives a [`WindowClassBuilder`] with its defaults. `configure_window` receives a [`WindowBuilder`] configured like [`Window::new_invisible()`] does. Passing both builders through unchanged is what `make_app()` does.
+        //!
+        //! # Safety
+        //! See [`AppLike::wnd_proc()`].
+
         let app = Rc::new(ReentrantRefCell::new(None::<App>));
-        let weak_app = Rc::downgrade(&app);
-
-        let window_class = WindowClass::new(move |hwnd, msg_id, wparam, lparam| {
-            // (`Weak` is necessary to prevent a circular dependency, which would prevent the `Drop` impl from being called.)
-            weak_app.upgrade().and_then(|app_cell| unsafe {
-                app_cell.borrow_mut_reentrant(|optional_app| match optional_app {
-                    None => {
-                        let (new_app, lresult) =
-                            App::startup_wnd_proc(hwnd, msg_id, wparam, lparam);
-                        *optional_app = new_app;
-                        lresult
-                    }
-                    Some(app) => app.wnd_proc(hwnd, msg_id, wparam, lparam),
-                })
-            })
-        })?;
+        let window_class =
+            configure_class(WindowClassBuilder::new()).build(app_wnd_proc(Rc::downgrade(&app)))?;
 
-        let window = Window::new_invisible(&window_class)?;
+        let window = configure_window(
+            WindowBuilder::new(&window_class)
+                .position(POINT::zeroed())
+                .size(SIZE::zeroed()),
+        )
+        .create()?;
 
         let helper = Self {
             _window_class: window_class,
@@ -47,6 +63,113 @@ impl<'a> InvisibleWindowAppHelper<'a> {
 
         Ok((helper, app))
     }
+
+    pub fn run_msg_loop<App>(
+        &self,
+        app: &ReentrantRefCell<Option<App>>,
+    ) -> windows::core::Result<usize>
+    where
+        App: AppLike<Self>,
+    {
+        //! Runs [`msg_loop::run()`] and, after `WM_QUIT` was received (also via [`msg_loop::quit_now()`]), calls [`AppLike::on_quit()`], if the app was made. Returns the exit code.
+        //!
+        //! `on_quit()` is also called when the message loop fails, before the error is returned.
+        //!
+        //! Use this instead of calling `msg_loop::run()` yourself, so that the app is notified before you drop it.
+
+        run_app_msg_loop(app)
+    }
+}
+
+/// Like [`InvisibleWindowAppHelper`], but for apps whose main UI is a regular visible top-level window.
+pub struct VisibleWindowAppHelper<'a> {
+    _window: Window,
+    _window_class: WindowClass<'a>,
+}
+
+impl<'a> VisibleWindowAppHelper<'a> {
+    pub unsafe fn make_app<App>(
+        title: &str,
+        size: SIZE,
+    ) -> windows::core::Result<(Self, Rc<ReentrantRefCell<Option<App>>>)>
+    where
+        App: AppLike<Self> + 'a,
+    {
+        //! Bootstraps an app with a `WS_OVERLAPPEDWINDOW | WS_VISIBLE` window with the given title and outer size at the default position. The window class has the defaults of [`WindowClassBuilder`].
+        //!
+        //! See [`InvisibleWindowAppHelper::make_app()`] regarding drop order.
+        //!
+        //! # Safety
+        //! See [`AppLike::wnd_proc()`].
+
+        let app = Rc::new(ReentrantRefCell::new(None::<App>));
+        let window_class = WindowClassBuilder::new().build(app_wnd_proc(Rc::downgrade(&app)))?;
+
+        let window = WindowBuilder::new(&window_class)
+            .style(WS_OVERLAPPEDWINDOW | WS_VISIBLE)
+            .size(size)
+            .text(title)
+            .create()?;
+
+        let helper = Self {
+            _window_class: window_class,
+            _window: window,
+        };
+
+        Ok((helper, app))
+    }
+
+    pub fn run_msg_loop<App>(
+        &self,
+        app: &ReentrantRefCell<Option<App>>,
+    ) -> windows::core::Result<usize>
+    where
+        App: AppLike<Self>,
+    {
+        //! See [`InvisibleWindowAppHelper::run_msg_loop()`].
+
+        run_app_msg_loop(app)
+    }
+}
+
+fn app_wnd_proc<'a, Helper, App>(weak_app: Weak<ReentrantRefCell<Option<App>>>) -> impl WndProc + 'a
+where
+    App: AppLike<Helper> + 'a,
+{
+    move |hwnd, msg_id, wparam, lparam| {
+        // (`Weak` is necessary to prevent a circular dependency, which would prevent the `Drop` impl from being called.)
+        weak_app.upgrade().and_then(|app_cell| unsafe {
+            app_cell.borrow_mut_reentrant(|optional_app| match optional_app {
+                None => {
+                    let (new_app, lresult) = App::startup_wnd_proc(hwnd, msg_id, wparam, lparam);
+                    *optional_app = new_app;
+                    lresult
+                }
+                Some(app) => app.wnd_proc(hwnd, msg_id, wparam, lparam),
+            })
+        })
+    }
+}
+
+fn run_app_msg_loop<Helper, App>(
+    app: &ReentrantRefCell<Option<App>>,
+) -> windows::core::Result<usize>
+where
+    App: AppLike<Helper>,
+{
+    // Also notify the app when the loop failed, before passing the error on.
+    let result = msg_loop::run();
+
+    // (`on_quit()` may reenter the window procedure, like `wnd_proc()`.)
+    unsafe {
+        app.borrow_mut_reentrant(|optional_app| {
+            if let Some(app) = optional_app {
+                app.on_quit();
+            }
+        })
+    };
+
+    result
 }
 
 pub trait AppLike<Helper>
@@ -78,6 +201,11 @@ where
         lparam: LPARAM,
     ) -> Option<LRESULT>;
 
+    /// Called once by the helper's message loop function (like [`InvisibleWindowAppHelper::run_msg_loop()`]) after the message loop quit (or failed), but before the app is dropped. A deterministic place to flush state and release resources in a certain order.
+    ///
+    /// Does nothing by default. The same rules as with [`Self::wnd_proc()`] apply.
+    fn on_quit(&mut self) {}
+
     /// A helper function that simply takes the same `self` parameter as [`Self::wnd_proc()`] to cause compiler errors, if necessary, when functions are called that synchronously call the window procedure and thus borrow `&mut self` again (via `ReentrantRefCell`). Anything other than simple reborrowing is against the rules. This prevents multiple simultaneous borrows.
     ///
     /// The function can be viewed as adding a `self` parameter to Windows API functions, as if they would belong to the type.
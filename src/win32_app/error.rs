@@ -1,6 +1,6 @@
 use super::msg_loop;
 use crate::windows;
-use std::cell::RefCell;
+use std::{cell::RefCell, fmt};
 use windows::Win32::UI::WindowsAndMessaging::PostQuitMessage;
 
 thread_local! {
@@ -66,6 +66,16 @@ where
     }
 }
 
+pub fn try_or_set_app_error_with_context<F, T, E>(context: &str, action: F) -> Option<T>
+where
+    F: FnOnce() -> Result<T, E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    //! Like [`try_or_set_app_error()`], but wraps the error in a [`ContextError`]. Its message is then, e.g., `"while initializing tray icon: <original message>"` for a `context` of `"while initializing tray icon"`.
+
+    try_or_set_app_error(|| action().map_err(|error| ContextError::new(context, error)))
+}
+
 pub fn try_or_post_quit<F, T, E>(action: F) -> Option<T>
 where
     F: FnOnce() -> Result<T, E>,
@@ -132,3 +142,69 @@ where
         result.map_err(|e| e.into())
     }
 }
+
+/// An error with a message prefix describing what was being done when the source error occurred.
+///
+/// The `Display` impl shows both the context and the source error's message. [`std::error::Error::source()`] returns the source error.
+#[derive(Debug)]
+pub struct ContextError {
+    context: String,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl ContextError {
+    pub fn new<E>(context: &str, source: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self {
+            context: context.to_owned(),
+            source: source.into(),
+        }
+    }
+
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.source)
+    }
+}
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{take_app_error, try_or_set_app_error_with_context};
+    use crate::windows;
+    use windows::Win32::Foundation::E_ACCESSDENIED;
+
+    #[test]
+    fn with_context() {
+        let option = try_or_set_app_error_with_context("while initializing tray icon", || {
+            Err::<(), _>(windows::core::Error::from(E_ACCESSDENIED))
+        });
+        assert!(option.is_none());
+
+        let error = take_app_error().unwrap();
+        let message = error.to_string();
+        assert!(message.starts_with("while initializing tray icon: "));
+        assert!(message.len() > "while initializing tray icon: ".len());
+
+        let source = error.source().unwrap();
+        assert_eq!(
+            source
+                .downcast_ref::<windows::core::Error>()
+                .unwrap()
+                .code(),
+            E_ACCESSDENIED
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{core::CheckNumberError, windows, Null};
+use windows::{
+    core::GUID,
+    Win32::{
+        Foundation::HMODULE,
+        Security::Cryptography::{BCryptHash, BCRYPT_SHA1_ALG_HANDLE},
+        System::LibraryLoader::GetModuleFileNameW,
+    },
+};
+
+/// The namespace for the name-based GUIDs of this crate. Must never change.
+const NAMESPACE_GUID: GUID = GUID::from_u128(0x98380de8_c18e_4aee_b06f_b03161abe254);
+
+pub fn stable_guid_for_current_exe() -> windows::core::Result<GUID> {
+    //! Derives a GUID from the path of the current executable (case-insensitively), like a version 5 UUID according to RFC 4122. The result is the same on every run, as long as the path doesn't change. For [`super::tray_icon::TrayIcon::with_guid()`].
+    //!
+    //! Note that the path is still an input, though: Since the system associates a tray icon GUID with the executable path, moving the executable would make adding the icon with an unchanged GUID fail. Deriving the GUID from the path avoids this, but the icon then loses its settings, like whether it's always shown, after such a move. See <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw#troubleshooting>.
+
+    let mut buffer = vec![0; 256];
+
+    let len = loop {
+        let len = unsafe { GetModuleFileNameW(HMODULE::NULL, &mut buffer) }
+            .nonzero_or_win32_err()? as usize;
+
+        // The path was truncated, if it filled the whole buffer.
+        if len < buffer.len() {
+            break len;
+        }
+
+        buffer.resize(buffer.len() * 2, 0);
+    };
+
+    let path = String::from_utf16(&buffer[..len])?.to_lowercase();
+
+    name_based_guid(NAMESPACE_GUID, path.as_bytes())
+}
+
+fn name_based_guid(namespace: GUID, name: &[u8]) -> windows::core::Result<GUID> {
+    let mut data = namespace.to_u128().to_be_bytes().to_vec();
+    data.extend_from_slice(name);
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&sha1(&data)?[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x50; // Version 5.
+    bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant.
+
+    Ok(GUID::from_u128(u128::from_be_bytes(bytes)))
+}
+
+fn sha1(data: &[u8]) -> windows::core::Result<[u8; 20]> {
+    // Not for cryptographic purposes, just as specified for version 5 UUIDs.
+
+    let mut digest = [0; 20];
+    unsafe { BCryptHash(BCRYPT_SHA1_ALG_HANDLE, None, data, &mut digest) }
+        .to_hresult()
+        .ok()?;
+
+    Ok(digest)
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{name_based_guid, sha1, stable_guid_for_current_exe};
+    use crate::windows;
+    use windows::core::GUID;
+
+    #[test]
+    fn sha1_digest() -> windows::core::Result<()> {
+        assert_eq!(
+            sha1(b"abc")?,
+            [
+                0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e, 0x25, 0x71, 0x78, 0x50,
+                0xc2, 0x6c, 0x9c, 0xd0, 0xd8, 0x9d
+            ]
+        );
+        assert_eq!(
+            // Multiple blocks.
+            sha1(&[b'a'; 1000])?,
+            [
+                0x29, 0x1e, 0x9a, 0x6c, 0x66, 0x99, 0x49, 0x49, 0xb5, 0x7b, 0xa5, 0xe6, 0x50, 0x36,
+                0x1e, 0x98, 0xfc, 0x36, 0xb1, 0xba
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_based_guid_like_uuid_v5() -> windows::core::Result<()> {
+        // The DNS namespace from RFC 4122.
+        let namespace = GUID::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+        assert_eq!(
+            name_based_guid(namespace, b"www.example.com")?,
+            GUID::from_u128(0x2ed6657d_e927_568b_95e1_2665a8aea6a2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn stable_guid() -> windows::core::Result<()> {
+        let guid = stable_guid_for_current_exe()?;
+        assert_eq!(stable_guid_for_current_exe()?, guid);
+        assert_eq!(guid.data3 >> 12, 5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{windows, Null, ResGuard};
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, WPARAM},
+        UI::WindowsAndMessaging::{
+            AppendMenuW, CreatePopupMenu, PostMessageW, SetForegroundWindow, TrackPopupMenu, HMENU,
+            MF_POPUP, MF_SEPARATOR, MF_STRING, TPM_RETURNCMD, TPM_RIGHTBUTTON, WM_NULL,
+        },
+    },
+};
+
+/// Configures a popup menu to be created with `CreatePopupMenu()` and `AppendMenuW()`, like a context menu for a tray icon.
+///
+/// Item IDs are reported by `WM_COMMAND` (see [`super::window::translate_command_msg()`]).
+#[derive(Default)]
+pub struct MenuBuilder {
+    items: Vec<MenuItem>,
+}
+
+enum MenuItem {
+    Item { id: u16, text: HSTRING },
+    Separator,
+    Submenu { text: HSTRING, builder: MenuBuilder },
+}
+
+impl MenuBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn item<T>(mut self, id: u16, text: T) -> Self
+    where
+        T: Into<HSTRING>,
+    {
+        //! Appends an item. The text may contain an ampersand to underline the following character as the mnemonic.
+
+        self.items.push(MenuItem::Item {
+            id,
+            text: text.into(),
+        });
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+
+    pub fn submenu<T>(mut self, text: T, builder: MenuBuilder) -> Self
+    where
+        T: Into<HSTRING>,
+    {
+        self.items.push(MenuItem::Submenu {
+            text: text.into(),
+            builder,
+        });
+        self
+    }
+
+    pub fn build(&self) -> windows::core::Result<ResGuard<HMENU>> {
+        //! Creates the menu with all items and submenus.
+        //!
+        //! The returned guard destroys the whole menu tree, since `DestroyMenu()` also destroys the submenus.
+
+        let h_menu = ResGuard::with_acq_and_destroy_menu(|| unsafe { CreatePopupMenu() })?;
+
+        for item in &self.items {
+            match item {
+                MenuItem::Item { id, text } => {
+                    unsafe { AppendMenuW(*h_menu, MF_STRING, *id as _, text) }?
+                }
+                MenuItem::Separator => {
+                    unsafe { AppendMenuW(*h_menu, MF_SEPARATOR, 0, PCWSTR::NULL) }?
+                }
+                MenuItem::Submenu { text, builder } => {
+                    let h_submenu = builder.build()?;
+                    unsafe { AppendMenuW(*h_menu, MF_STRING | MF_POPUP, h_submenu.0 as _, text) }?;
+
+                    // Owned by the parent menu now.
+                    h_submenu.release();
+                }
+            }
+        }
+
+        Ok(h_menu)
+    }
+}
+
+pub fn track_context_menu(
+    hwnd: HWND,
+    menu: HMENU,
+    x: i16,
+    y: i16,
+) -> windows::core::Result<Option<u16>> {
+    //! Shows the popup menu at the virtual-screen coordinates, like from [`super::tray_icon::SimplifiedTrayIconMsg::ContextMenuRequested`], and returns the ID of the chosen item, or `None` if the menu was dismissed.
+    //!
+    //! Makes `hwnd` the foreground window beforehand and posts `WM_NULL` to it afterwards, as required for the menu to be dismissed properly when clicking elsewhere (see remarks of [`TrackPopupMenu()`][1]).
+    //!
+    //! `TrackPopupMenu()` runs a modal message loop, during which the window procedure is called again. When calling the function from code that has an app borrowed from a [`crate::ReentrantRefCell`], the borrow must have been made with [`crate::ReentrantRefCell::borrow_mut_reentrant()`] (as done by the app helpers).
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-trackpopupmenu
+
+    let _ = unsafe { SetForegroundWindow(hwnd) };
+
+    // With `TPM_RETURNCMD`, 0 means that the menu was dismissed. `GetLastError()` isn't meaningful then, since window procedures may have set it during the modal loop.
+    let id = unsafe {
+        TrackPopupMenu(
+            menu,
+            TPM_RETURNCMD | TPM_RIGHTBUTTON,
+            x.into(),
+            y.into(),
+            0,
+            hwnd,
+            None,
+        )
+    }
+    .0;
+
+    unsafe { PostMessageW(hwnd, WM_NULL, WPARAM(0), LPARAM(0)) }?;
+
+    Ok((id != 0).then_some(id as _))
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{track_context_menu, MenuBuilder};
+    use crate::{
+        win32_app::window::{Window, WindowClass},
+        windows,
+    };
+    use windows::Win32::{
+        Foundation::POINT,
+        UI::WindowsAndMessaging::{GetCursorPos, GetMenuItemCount, GetMenuItemID, GetSubMenu},
+    };
+
+    #[test]
+    fn build() -> windows::core::Result<()> {
+        let h_menu = MenuBuilder::new()
+            .item(1, "&Open")
+            .separator()
+            .submenu(
+                "&More",
+                MenuBuilder::new().item(2, "First").item(3, "Second"),
+            )
+            .item(4, "E&xit")
+            .build()?;
+
+        assert_eq!(unsafe { GetMenuItemCount(*h_menu) }, 4);
+        assert_eq!(unsafe { GetMenuItemID(*h_menu, 0) }, 1);
+        assert_eq!(unsafe { GetMenuItemID(*h_menu, 2) }, u32::MAX); // Submenu.
+        assert_eq!(unsafe { GetMenuItemID(*h_menu, 3) }, 4);
+
+        let h_submenu = unsafe { GetSubMenu(*h_menu, 2) };
+        assert_eq!(unsafe { GetMenuItemCount(h_submenu) }, 2);
+        assert_eq!(unsafe { GetMenuItemID(h_submenu, 1) }, 3);
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[test]
+    fn track_context_menu_at_cursor() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        let h_menu = MenuBuilder::new()
+            .item(1, "Choose &me")
+            .item(2, "Or &me")
+            .build()?;
+
+        let mut cursor_pos = POINT::default();
+        unsafe { GetCursorPos(&mut cursor_pos) }?;
+
+        let id = track_context_menu(window.hwnd(), *h_menu, cursor_pos.x as _, cursor_pos.y as _)?;
+        println!("chosen item: {id:?}");
+        assert!(matches!(id, None | Some(1 | 2)));
+
+        Ok(())
+    }
+}
@@ -7,7 +7,8 @@ use std::cell::Cell;
 use windows::Win32::{
     Foundation::{HWND, LPARAM, WPARAM},
     UI::WindowsAndMessaging::{
-        DispatchMessageW, GetMessageW, PostQuitMessage, TranslateMessage, MSG, WM_QUIT,
+        DispatchMessageW, GetMessageW, IsDialogMessageW, PeekMessageW, PostQuitMessage,
+        TranslateAcceleratorW, TranslateMessage, HACCEL, MSG, PM_NOREMOVE, PM_REMOVE, WM_QUIT,
     },
 };
 
@@ -34,6 +35,132 @@ pub fn run() -> windows::core::Result<usize> {
     }
 }
 
+pub fn run_with_accelerators(hwnd: HWND, h_accel: HACCEL) -> windows::core::Result<usize> {
+    //! Like [`run()`], but lets [`TranslateAcceleratorW()`][1] process every message first, so that keyboard accelerators work. Messages it processed aren't translated and dispatched again.
+    //!
+    //! The accelerator table can be created with [`CreateAcceleratorTableW()`][2] and guarded with [`crate::ResGuard::with_acq_and_destroy_accelerator_table()`].
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-translateacceleratorw
+    //! [2]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createacceleratortablew
+
+    loop {
+        let msg = run_till_thread_msg_with(
+            || {},
+            |_| {},
+            |msg| unsafe { TranslateAcceleratorW(hwnd, h_accel, msg) != 0 },
+        )?;
+        if msg.message == WM_QUIT {
+            break Ok(msg.wParam.0);
+        }
+    }
+}
+
+pub fn run_with_dialog(dialogs: &[HWND]) -> windows::core::Result<usize> {
+    //! Like [`run()`], but lets [`IsDialogMessageW()`][1] process every message first for each of the modeless dialogs, so that keyboard navigation with Tab, arrow keys etc. works in them. Messages a dialog processed aren't translated and dispatched again.
+    //!
+    //! See [Using Dialog Boxes][2].
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isdialogmessagew
+    //! [2]: https://learn.microsoft.com/en-us/windows/win32/dlgbox/using-dialog-boxes#creating-a-modeless-dialog-box
+
+    loop {
+        let msg = run_till_thread_msg_with(
+            || {},
+            |_| {},
+            |msg| {
+                dialogs
+                    .iter()
+                    .any(|&dialog| unsafe { IsDialogMessageW(dialog, msg) }.as_bool())
+            },
+        )?;
+        if msg.message == WM_QUIT {
+            break Ok(msg.wParam.0);
+        }
+    }
+}
+
+pub fn run_with_idle<F>(mut on_idle: F) -> windows::core::Result<usize>
+where
+    F: FnMut() -> bool,
+{
+    //! Like [`run()`], but calls the closure whenever the message queue is empty, before waiting for the next message.
+    //!
+    //! The closure returns whether it wants to be called again. With `true`, it's called again right away, unless messages arrived in the meantime. With `false`, the loop waits for the next message as usual. For background work tied to the UI thread, which should be done in small portions.
+
+    loop {
+        let msg = run_till_thread_msg_with(
+            || {
+                while !unsafe { PeekMessageW(&mut MSG::default(), HWND::NULL, 0, 0, PM_NOREMOVE) }
+                    .as_bool()
+                {
+                    if !on_idle() {
+                        break;
+                    }
+                }
+            },
+            |_| {},
+            |_| false,
+        )?;
+        if msg.message == WM_QUIT {
+            break Ok(msg.wParam.0);
+        }
+    }
+}
+
+pub fn run_with_observer<F>(mut observer: F) -> windows::core::Result<usize>
+where
+    F: FnMut(&MSG),
+{
+    //! Like [`run()`], but passes every retrieved message to the closure before it's dispatched. For diagnostic purposes.
+    //!
+    //! The closure also receives the final `WM_QUIT` message, including the one produced by [`quit_now()`].
+
+    loop {
+        let msg = run_till_thread_msg_with(|| {}, &mut observer, |_| false)?;
+        if msg.message == WM_QUIT {
+            break Ok(msg.wParam.0);
+        }
+    }
+}
+
+pub fn run_until<F>(mut should_stop: F) -> windows::core::Result<()>
+where
+    F: FnMut(&MSG) -> bool,
+{
+    //! Runs a nested message loop, e.g., for a custom modal dialog, until the closure returns `true`. The closure is called with every message after it was dispatched, so it can, e.g., check whether a certain window still exists.
+    //!
+    //! If [`WM_QUIT`][1] is received (also via [`quit_now()`]) before that, the function re-posts it with [`PostQuitMessage()`][2] and the same exit code, so that the outer message loop also quits, and returns.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-quit
+    //! [2]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postquitmessage
+
+    let mut msg = MSG::default();
+
+    loop {
+        let get_msg_retval = unsafe { GetMessageW(&mut msg, HWND::NULL, 0, 0).0 };
+
+        if get_msg_retval == -1 {
+            break Result::err_from_win32();
+        } else if let Some(exit_code) = QUIT_NOW_EXIT_CODE.get() {
+            // The exit code stays saved, so the outer loop's next message is also changed.
+            unsafe { PostQuitMessage(exit_code) };
+            break Ok(());
+        } else if get_msg_retval == 0 {
+            unsafe { PostQuitMessage(msg.wParam.0 as _) };
+            break Ok(());
+        } else {
+            unsafe {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if should_stop(&msg) {
+                break Ok(());
+            }
+        }
+    }
+}
+
 pub fn run_till_thread_msg() -> windows::core::Result<MSG> {
     //! Runs a message loop until a thread message is received.
     //!
@@ -44,9 +171,26 @@ pub fn run_till_thread_msg() -> windows::core::Result<MSG> {
     //! [3]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postthreadmessagew
     //! [4]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postmessagew
 
+    run_till_thread_msg_with(|| {}, |_| {}, |_| false)
+}
+
+fn run_till_thread_msg_with<W, O, P>(
+    mut before_wait: W,
+    mut observe: O,
+    mut pre_translate: P,
+) -> windows::core::Result<MSG>
+where
+    W: FnMut(),
+    O: FnMut(&MSG),
+    P: FnMut(&MSG) -> bool,
+{
+    // `before_wait` is called before every `GetMessageW()` call. `observe` is called with every retrieved message, including `WM_QUIT`. `pre_translate` returns whether it processed the message, in which case it isn't translated and dispatched.
+
     let mut msg = MSG::default();
 
     loop {
+        before_wait();
+
         // (`GetMessageW()` calls hook callbacks without returning.)
         let mut get_msg_retval = unsafe { GetMessageW(&mut msg, HWND::NULL, 0, 0).0 };
 
@@ -61,6 +205,8 @@ pub fn run_till_thread_msg() -> windows::core::Result<MSG> {
                 msg.lParam = LPARAM(0);
             }
 
+            observe(&msg);
+
             if get_msg_retval == 0 {
                 // Received `WM_QUIT` thread message. Caller must check `msg.message` against `WM_QUIT`.
                 // (`GetMessageW()` return value is checked instead of treating `WM_QUIT` like all thread messages, in case abusive behavior caused `msg.hwnd` to be non-zero, which is possible via `PostMessageW()`.)
@@ -68,9 +214,11 @@ pub fn run_till_thread_msg() -> windows::core::Result<MSG> {
             } else {
                 // Propagate window message to window procedure.
                 // As confirmed by a test, `DispatchMessageW()` also calls the timer callback on `WM_TIMER` when `msg.hwnd` is 0. Official example code also does it this way. (https://learn.microsoft.com/en-us/windows/win32/winmsg/using-messages-and-message-queues) So, the calls are just made for all thread messages. Custom thread messages are ignored by them. (Docs: "DispatchMessage will call the TimerProc callback function specified in the call to the SetTimer function used to install the timer." [https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-timer])
-                unsafe {
-                    TranslateMessage(&msg);
-                    DispatchMessageW(&msg);
+                if !pre_translate(&msg) {
+                    unsafe {
+                        TranslateMessage(&msg);
+                        DispatchMessageW(&msg);
+                    }
                 }
 
                 // Return thread message.
@@ -82,6 +230,34 @@ pub fn run_till_thread_msg() -> windows::core::Result<MSG> {
     }
 }
 
+pub fn pump_pending() -> windows::core::Result<Option<i32>> {
+    //! Dispatches all messages that are currently in the queue, using [`PeekMessageW()`][1], without waiting for new ones. Returns immediately when the queue is empty.
+    //!
+    //! For integrating with another event loop. Returns `Some` with the exit code, if [`WM_QUIT`][2] was received (also via [`quit_now()`]), in which case the remaining messages are left in the queue.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-peekmessagew
+    //! [2]: https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-quit
+
+    let mut msg = MSG::default();
+
+    while unsafe { PeekMessageW(&mut msg, HWND::NULL, 0, 0, PM_REMOVE) }.as_bool() {
+        if let Some(exit_code) = QUIT_NOW_EXIT_CODE.get() {
+            return Ok(Some(exit_code));
+        }
+
+        if msg.message == WM_QUIT {
+            return Ok(Some(msg.wParam.0 as _));
+        }
+
+        unsafe {
+            TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+
+    Ok(None)
+}
+
 pub fn quit_now(exit_code: i32) {
     //! Causes the message loop to quit as soon as possible.
     //!
@@ -95,12 +271,26 @@ pub fn quit_now(exit_code: i32) {
 
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
-    use crate::{windows, Null};
+    use crate::{
+        win32_app::window::{Window, WindowClass},
+        windows, Null,
+    };
     use windows::Win32::{
-        Foundation::HWND,
-        UI::WindowsAndMessaging::{PostQuitMessage, SetTimer},
+        Foundation::{HWND, LPARAM, WPARAM},
+        System::Threading::GetCurrentThreadId,
+        UI::WindowsAndMessaging::{PostQuitMessage, PostThreadMessageW, SetTimer, WM_APP, WM_QUIT},
     };
 
+    #[test]
+    fn pump_pending() -> windows::core::Result<()> {
+        assert_eq!(super::pump_pending()?, None);
+
+        unsafe { PostQuitMessage(3) };
+        assert_eq!(super::pump_pending()?, Some(3));
+
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn set_timer() -> windows::core::Result<()> {
@@ -117,4 +307,90 @@ mod tests {
 
         Ok(())
     }
+
+    #[ignore]
+    #[test]
+    fn run_with_idle() -> windows::core::Result<()> {
+        extern "system" fn on_timer(_hwnd: HWND, _msg_id: u32, _event_id: usize, _time: u32) {
+            unsafe { PostQuitMessage(0) };
+        }
+
+        unsafe {
+            SetTimer(HWND::NULL, 0, 500 /*ms*/, Some(on_timer))
+        };
+
+        let mut num_idle_calls = 0;
+        super::run_with_idle(|| {
+            num_idle_calls += 1;
+            num_idle_calls % 3 != 0
+        })?;
+        println!("idle calls: {num_idle_calls}");
+        assert!(num_idle_calls >= 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_with_dialog() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        unsafe { PostThreadMessageW(GetCurrentThreadId(), WM_APP, WPARAM(0), LPARAM(0)) }?;
+        unsafe { PostQuitMessage(4) };
+        assert_eq!(super::run_with_dialog(&[window.hwnd()])?, 4);
+
+        super::quit_now(8);
+        assert_eq!(super::run_with_dialog(&[])?, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_until() -> windows::core::Result<()> {
+        let thread_id = unsafe { GetCurrentThreadId() };
+        unsafe { PostThreadMessageW(thread_id, WM_APP + 1, WPARAM(0), LPARAM(0)) }?;
+        unsafe { PostThreadMessageW(thread_id, WM_APP, WPARAM(0), LPARAM(0)) }?;
+        unsafe { PostThreadMessageW(thread_id, WM_APP + 2, WPARAM(0), LPARAM(0)) }?;
+
+        let mut msg_ids = Vec::new();
+        super::run_until(|msg| {
+            msg_ids.push(msg.message);
+            msg.message == WM_APP
+        })?;
+        assert_eq!(msg_ids, [WM_APP + 1, WM_APP]);
+
+        // `WM_QUIT` is re-posted for the outer loop.
+        unsafe { PostQuitMessage(9) };
+        msg_ids.clear();
+        super::run_until(|msg| {
+            msg_ids.push(msg.message);
+            false
+        })?;
+        assert_eq!(msg_ids, [WM_APP + 2]);
+        assert_eq!(super::run()?, 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_with_observer() -> windows::core::Result<()> {
+        let mut msg_ids = Vec::new();
+
+        unsafe { PostThreadMessageW(GetCurrentThreadId(), WM_APP, WPARAM(0), LPARAM(0)) }?;
+        unsafe { PostQuitMessage(5) };
+
+        let exit_code = super::run_with_observer(|msg| msg_ids.push(msg.message))?;
+        assert_eq!(exit_code, 5);
+        assert_eq!(msg_ids, [WM_APP, WM_QUIT]);
+
+        // The message retrieved next is replaced with `WM_QUIT`.
+        msg_ids.clear();
+        super::quit_now(6);
+
+        let exit_code = super::run_with_observer(|msg| msg_ids.push(msg.message))?;
+        assert_eq!(exit_code, 6);
+        assert_eq!(msg_ids, [WM_QUIT]);
+
+        Ok(())
+    }
 }
@@ -2,17 +2,19 @@ use crate::{
     bit_manipulation::{build_bit_flag_set, Width32BitPortion},
     core::HStringExt,
     foundation::BoolExt,
-    windows, Null,
+    windows, Null, ResGuard,
 };
 use map_self::MapSelf;
 use std::{
+    collections::HashMap,
     mem,
+    sync::atomic::{AtomicU32, Ordering},
     time::{Duration, Instant},
 };
 use windows::{
-    core::{GUID, HSTRING},
+    core::{w, GUID, HSTRING, PCWSTR},
     Win32::{
-        Foundation::{HWND, LPARAM, RECT, WPARAM},
+        Foundation::{HINSTANCE, HMODULE, HWND, LPARAM, RECT, WPARAM},
         UI::{
             Input::KeyboardAndMouse::GetDoubleClickTime,
             Shell::{
@@ -20,11 +22,15 @@ use windows::{
                 NIF_MESSAGE, NIF_REALTIME, NIF_SHOWTIP, NIF_STATE, NIF_TIP, NIIF_ERROR, NIIF_INFO,
                 NIIF_LARGE_ICON, NIIF_NONE, NIIF_NOSOUND, NIIF_RESPECT_QUIET_TIME, NIIF_USER,
                 NIIF_WARNING, NIM_ADD, NIM_DELETE, NIM_MODIFY, NIM_SETFOCUS, NIM_SETVERSION,
-                NINF_KEY, NIN_SELECT, NIS_HIDDEN, NOTIFYICONDATAW, NOTIFYICONDATAW_0,
+                NINF_KEY, NIN_BALLOONHIDE, NIN_BALLOONSHOW, NIN_BALLOONTIMEOUT,
+                NIN_BALLOONUSERCLICK, NIN_SELECT, NIS_HIDDEN, NOTIFYICONDATAW, NOTIFYICONDATAW_0,
                 NOTIFYICONIDENTIFIER, NOTIFYICON_VERSION_4, NOTIFY_ICON_DATA_FLAGS,
                 NOTIFY_ICON_INFOTIP_FLAGS, NOTIFY_ICON_STATE,
             },
-            WindowsAndMessaging::{HICON, WM_CONTEXTMENU},
+            WindowsAndMessaging::{
+                GetSystemMetrics, LoadImageW, RegisterWindowMessageW, HICON, IMAGE_ICON,
+                LR_DEFAULTCOLOR, SM_CXSMICON, SM_CYSMICON, WM_CONTEXTMENU,
+            },
         },
     },
 };
@@ -34,7 +40,7 @@ const NIN_KEYSELECT: u32 = NIN_SELECT | NINF_KEY;
 
 /// An abstraction over `Shell_NotifyIconW()`.
 ///
-/// The icon is initially hidden and must be shown with `show()`.
+/// The icon is initially hidden and must be shown with `show()`, unless it was created with [`TrayIconBuilder`].
 ///
 /// To avoid fetching a low-quality icon, the app's manifest must declare it as fully DPI-aware (or jump through other hoops to get an appropriately sized icon).
 pub struct TrayIcon {
@@ -44,7 +50,7 @@ pub struct TrayIcon {
 
 impl TrayIcon {
     pub fn with_primary_id(hwnd: HWND, window_msg_id: Option<u32>) -> windows::core::Result<Self> {
-        //! Creates a tray icon with ID 0. If you need more than one tray icon, don't use this function repeatedly.
+        //! Creates a tray icon with ID 0. If you need more than one tray icon, don't use this function repeatedly, but use a [`TrayIconSet`].
 
         Self::with_id(0, hwnd, window_msg_id)
     }
@@ -71,7 +77,27 @@ impl TrayIcon {
         hwnd: HWND,
         window_msg_id: Option<u32>,
     ) -> windows::core::Result<Self> {
-        let notify_icon_data = NOTIFYICONDATAW {
+        TrayIconBuilder {
+            id,
+            guid,
+            window_msg_id,
+            ..TrayIconBuilder::new(hwnd)
+        }
+        .build()
+    }
+
+    fn from_builder(builder: TrayIconBuilder) -> windows::core::Result<Self> {
+        let TrayIconBuilder {
+            hwnd,
+            id,
+            guid,
+            window_msg_id,
+            h_icon,
+            tooltip,
+            shown,
+        } = builder;
+
+        let mut notify_icon_data = NOTIFYICONDATAW {
             cbSize: mem::size_of::<NOTIFYICONDATAW>() as _,
             hWnd: hwnd,
             // `id` has to be `u16`. See docs of `uCallbackMessage` field.
@@ -81,11 +107,17 @@ impl TrayIcon {
                 | build_bit_flag_set([
                     (guid.is_some(), NIF_GUID),
                     (window_msg_id.is_some(), NIF_MESSAGE),
+                    (!h_icon.is_null(), NIF_ICON),
+                    (tooltip.is_some(), NIF_TIP | NIF_SHOWTIP),
                 ]),
             uCallbackMessage: window_msg_id.unwrap_or_default(),
-            hIcon: HICON::NULL,
+            hIcon: h_icon,
             szTip: [0; 128],
-            dwState: NIS_HIDDEN,
+            dwState: if shown {
+                NOTIFY_ICON_STATE(0)
+            } else {
+                NIS_HIDDEN
+            },
             dwStateMask: {
                 #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
                 {
@@ -106,6 +138,10 @@ impl TrayIcon {
             hBalloonIcon: HICON::NULL,
         };
 
+        if let Some(tooltip) = tooltip {
+            tooltip.write_truncated(&mut notify_icon_data.szTip);
+        }
+
         if guid.is_some() {
             // If the app is forcefully terminated, so that is can't call the `NIM_DELETE` command, the icon continues to linger in the tray until a mouse-move event. If this event doesn't occur and the app is restarted, `NIM_ADD` without this previous `NIM_DELETE` would fail. (The icon with the GUID seems to still count as registered and alive.)
             unsafe { Shell_NotifyIconW(NIM_DELETE, &notify_icon_data) };
@@ -135,7 +171,7 @@ impl TrayIcon {
     pub fn readd(&self) -> windows::core::Result<()> {
         //! Adds the icon again.
         //!
-        //! Only to be called when receiving the window message `RegisterWindowMessageW(w!("TaskbarCreated"))`, which is also sent when `explorer.exe` restarted.
+        //! Only to be called when receiving the window message `RegisterWindowMessageW(w!("TaskbarCreated"))`, which is also sent when `explorer.exe` restarted. See [`Self::handle_taskbar_created()`].
 
         unsafe {
             for action in [NIM_ADD, NIM_SETVERSION] {
@@ -151,6 +187,33 @@ impl TrayIcon {
         Ok(())
     }
 
+    pub fn taskbar_created_message_id() -> u32 {
+        //! Returns the ID of the window message `RegisterWindowMessageW(w!("TaskbarCreated"))`, which is registered on the first call. The ID is the same for all processes until the system is restarted. Returns 0 if registering failed.
+
+        static TASKBAR_CREATED_MSG_ID: AtomicU32 = AtomicU32::new(0);
+
+        let mut msg_id = TASKBAR_CREATED_MSG_ID.load(Ordering::Relaxed);
+        if msg_id == 0 {
+            msg_id = unsafe { RegisterWindowMessageW(w!("TaskbarCreated")) };
+            TASKBAR_CREATED_MSG_ID.store(msg_id, Ordering::Relaxed);
+        }
+
+        msg_id
+    }
+
+    pub fn handle_taskbar_created(&self, msg_id: u32) -> windows::core::Result<bool> {
+        //! Calls [`Self::readd()`], if the window message ID is the one from [`Self::taskbar_created_message_id()`]. Returns whether it did.
+        //!
+        //! To be called from the window procedure of the icon's window, so that the icon reappears when `explorer.exe` restarted. The window must not be a message-only window, because the message is broadcast.
+
+        if is_taskbar_created_msg(msg_id) {
+            self.readd()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
     pub fn rect(&self) -> windows::core::Result<RECT> {
         //! Calls [`Shell_NotifyIconGetRect()`][1].
         //!
@@ -180,7 +243,35 @@ impl TrayIcon {
         self.notify_icon_data.uFlags |= NIF_ICON;
         self.notify_icon_data.hIcon = h_icon;
 
-        self.call_modify()
+        self.call_modify(NIF_ICON)
+    }
+
+    pub fn set_icon_from_module_resource(
+        &mut self,
+        module: HMODULE,
+        resource_id: u16,
+    ) -> windows::core::Result<ResGuard<HICON>> {
+        //! Loads an icon resource in the small icon size of the system's DPI with [`LoadImageW()`][1] and sets it as the new icon.
+        //!
+        //! Keep the returned guard alive until the icon has been replaced or this [`TrayIcon`] has been dropped, as with [`Self::set_icon()`].
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew
+
+        let h_icon = ResGuard::with_acq_and_destroy_icon(|| unsafe {
+            LoadImageW(
+                HINSTANCE(module.0),
+                PCWSTR(resource_id as usize as _), // `MAKEINTRESOURCEW()`
+                IMAGE_ICON,
+                GetSystemMetrics(SM_CXSMICON),
+                GetSystemMetrics(SM_CYSMICON),
+                LR_DEFAULTCOLOR,
+            )
+            .map(|handle| HICON(handle.0))
+        })?;
+
+        unsafe { self.set_icon(*h_icon)? };
+
+        Ok(h_icon)
     }
 
     pub fn set_tooltip<T>(&mut self, tooltip: Option<T>) -> windows::core::Result<()>
@@ -198,14 +289,24 @@ impl TrayIcon {
 
             self.notify_icon_data.uFlags |= FLAGS;
             hstring.write_truncated(&mut self.notify_icon_data.szTip);
+
+            self.call_modify(FLAGS)
         } else {
             self.notify_icon_data.uFlags &= !FLAGS;
-        }
+            self.notify_icon_data.szTip[0] = 0;
 
-        self.call_modify()
+            // Without `NIF_SHOWTIP`, the standard tooltip isn't shown.
+            self.call_modify(NIF_TIP)
+        }
     }
 
-    pub fn show(&mut self, show: bool) -> windows::core::Result<()> {
+    pub fn show(&mut self, show: bool) -> windows::core::Result<bool> {
+        //! Shows or hides the icon. Returns whether the state changed, since nothing is done if the icon already is in the requested state, which also avoids needless calls to the shell when calling this function repeatedly.
+
+        if show == self.is_shown() {
+            return Ok(false);
+        }
+
         //TODO: Change expected in `windows` v0.53. More uses than just here. See <https://github.com/microsoft/win32metadata/issues/1767>.
         if show {
             self.notify_icon_data.dwState.0 &= !NIS_HIDDEN.0;
@@ -223,7 +324,11 @@ impl TrayIcon {
             }
         };
 
-        let result = self.call_modify();
+        let result = self.call_modify(NIF_STATE);
+        if result.is_err() {
+            // Allow retrying.
+            self.notify_icon_data.dwState.0 ^= NIS_HIDDEN.0;
+        }
         self.notify_icon_data.dwStateMask = {
             #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
             {
@@ -235,13 +340,35 @@ impl TrayIcon {
             }
         };
 
-        result
+        result.map(|()| true)
     }
 
     pub fn is_shown(&self) -> bool {
         (self.notify_icon_data.dwState.0 & NIS_HIDDEN.0) == 0
     }
 
+    pub fn id(&self) -> u16 {
+        //! Returns the ID, which is 0 for icons identified by a GUID.
+
+        self.notify_icon_data.uID as _
+    }
+
+    pub fn guid(&self) -> Option<GUID> {
+        ((self.notify_icon_data.uFlags & NIF_GUID).0 != 0).then_some(self.notify_icon_data.guidItem)
+    }
+
+    pub fn tooltip(&self) -> String {
+        //! Returns the (possibly truncated) tooltip text, or an empty string, if there's none.
+
+        let sz_tip = &self.notify_icon_data.szTip;
+        let len = sz_tip
+            .iter()
+            .position(|&wide_char| wide_char == 0)
+            .unwrap_or(sz_tip.len());
+
+        String::from_utf16_lossy(&sz_tip[..len])
+    }
+
     pub fn focus<T>(&mut self) -> windows::core::Result<()> {
         //! Performs the `NIM_SETFOCUS` command.
         //!
@@ -259,7 +386,9 @@ impl TrayIcon {
         //! See [`Self::set_icon()`].
 
         self.notify_icon_data.hBalloonIcon = h_icon.unwrap_or(HICON::NULL);
-        self.call_modify()
+
+        // Only relevant for the next balloon notification. Sending it with `NIF_INFO` now would hide a shown notification.
+        Ok(())
     }
 
     pub fn set_balloon_uses_large_icon(&mut self, uses_large_icon: bool) {
@@ -288,12 +417,38 @@ impl TrayIcon {
     where
         T: Into<HSTRING>,
     {
+        //! Like [`Self::show_balloon_with()`], with positional parameters. The large icon setting from [`Self::set_balloon_uses_large_icon()`] is used.
+
+        self.show_balloon_with(BalloonOptions {
+            icon,
+            title: title.map(Into::into),
+            text: text.into(),
+            realtime_only,
+            override_quiet_time,
+            allow_sound,
+            large_icon: (self.notify_icon_data.dwInfoFlags.0 & NIIF_LARGE_ICON.0) != 0,
+        })
+    }
+
+    pub fn show_balloon_with(&mut self, options: BalloonOptions) -> windows::core::Result<()> {
         //! Shows a so-called balloon notification that will automatically be hidden after a while. Not really a balloon anymore on modern Windows versions, but a regular notification.
         //!
-        //! Long texts will be truncated. For more information on this and the parameters, see <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw>.
+        //! Long texts will be truncated. For more information on this and the options, see <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw>.
         //!
         //! Doesn't return an error, if the notification is suppressed.
 
+        let BalloonOptions {
+            icon,
+            title,
+            text,
+            realtime_only,
+            override_quiet_time,
+            allow_sound,
+            large_icon,
+        } = options;
+
+        self.set_balloon_uses_large_icon(large_icon);
+
         self.notify_icon_data.dwInfoFlags.0 |= match icon {
             BalloonIcon::None => NIIF_NONE.0,
             BalloonIcon::Info => NIIF_INFO.0,
@@ -303,7 +458,6 @@ impl TrayIcon {
         };
 
         if let Some(title) = title {
-            let title: HSTRING = title.into();
             title.write_truncated(&mut self.notify_icon_data.szInfoTitle);
 
             // Note: The docs (as of Dec. 2023) say: "If the szInfoTitle member is zero-length, the icon is not shown." On Windows 10, this isn't true. But the OS trims the string, and then, if it's empty, displays the message text in title style. This can extend this function's `Option` semantics, but the user should either not notice this behavior or not find it objectionable.
@@ -311,7 +465,6 @@ impl TrayIcon {
             self.notify_icon_data.szInfoTitle[0] = 0;
         }
 
-        let text: HSTRING = text.into();
         if text.is_empty() {
             const SPACE_WIDE_STR: [u16; 2] = [' ' as _, 0];
 
@@ -321,9 +474,11 @@ impl TrayIcon {
             text.write_truncated(&mut self.notify_icon_data.szInfo);
         }
 
-        if realtime_only {
-            self.notify_icon_data.uFlags |= NIF_REALTIME;
-        }
+        let realtime_flag = if realtime_only {
+            NIF_REALTIME
+        } else {
+            NOTIFY_ICON_DATA_FLAGS(0)
+        };
         if !override_quiet_time {
             self.notify_icon_data.dwInfoFlags.0 |= NIIF_RESPECT_QUIET_TIME.0;
         }
@@ -331,10 +486,9 @@ impl TrayIcon {
             self.notify_icon_data.dwInfoFlags.0 |= NIIF_NOSOUND.0;
         }
 
-        let result = self.call_modify();
+        let result = self.call_modify(NIF_INFO | realtime_flag);
 
-        self.notify_icon_data.szInfo[0] = 0; // Prevent new notification on other change.
-        self.notify_icon_data.uFlags &= !NIF_REALTIME;
+        self.notify_icon_data.szInfo[0] = 0; // Prevent new notification on `readd()`.
         self.notify_icon_data.dwInfoFlags.0 &= !(NIIF_NONE.0
             | NIIF_INFO.0
             | NIIF_WARNING.0
@@ -348,7 +502,7 @@ impl TrayIcon {
 
     pub fn hide_balloon(&mut self) -> windows::core::Result<()> {
         self.notify_icon_data.szInfo[0] = 0;
-        self.call_modify()
+        self.call_modify(NIF_INFO)
     }
 
     pub fn simplifying_translate_window_msg(
@@ -378,6 +532,11 @@ impl TrayIcon {
             // Context menu request via mouse or keyboard.
             WM_CONTEXTMENU => SimplifiedTrayIconMsg::ContextMenuRequested { x: msg.x, y: msg.y },
 
+            NIN_BALLOONUSERCLICK => SimplifiedTrayIconMsg::BalloonClicked,
+            NIN_BALLOONTIMEOUT => SimplifiedTrayIconMsg::BalloonTimedOut,
+            NIN_BALLOONSHOW => SimplifiedTrayIconMsg::BalloonShown,
+            NIN_BALLOONHIDE => SimplifiedTrayIconMsg::BalloonHidden,
+
             _ => SimplifiedTrayIconMsg::Other(msg),
         }
     }
@@ -390,8 +549,16 @@ impl TrayIcon {
         unsafe { Shell_NotifyIconW(NIM_DELETE, &self.notify_icon_data).ok_or_e_fail() }
     }
 
-    fn call_modify(&mut self) -> windows::core::Result<()> {
-        unsafe { Shell_NotifyIconW(NIM_MODIFY, &self.notify_icon_data).ok_or_e_fail() }
+    fn call_modify(&mut self, changed_flags: NOTIFY_ICON_DATA_FLAGS) -> windows::core::Result<()> {
+        // `uFlags` holds the flags of all configured members, as needed by `readd()`. A modification only advertises the changed members, plus `NIF_GUID` that identifies the icon.
+        let all_flags = self.notify_icon_data.uFlags;
+        self.notify_icon_data.uFlags = (all_flags & NIF_GUID) | changed_flags;
+
+        let result =
+            unsafe { Shell_NotifyIconW(NIM_MODIFY, &self.notify_icon_data).ok_or_e_fail() };
+        self.notify_icon_data.uFlags = all_flags;
+
+        result
     }
 }
 
@@ -404,6 +571,180 @@ impl Drop for TrayIcon {
     }
 }
 
+/// Configures a [`TrayIcon`] before it's added to the tray.
+///
+/// Unlike the constructors of [`TrayIcon`], this allows to add the icon with its icon image, tooltip and visibility in one go, avoiding a visible intermediate state.
+pub struct TrayIconBuilder {
+    hwnd: HWND,
+    id: Option<u16>,
+    guid: Option<GUID>,
+    window_msg_id: Option<u32>,
+    h_icon: HICON,
+    tooltip: Option<HSTRING>,
+    shown: bool,
+}
+
+impl TrayIconBuilder {
+    pub fn new(hwnd: HWND) -> Self {
+        //! Starts with ID 0, no window message, no icon image, no tooltip and a hidden icon.
+
+        Self {
+            hwnd,
+            id: None,
+            guid: None,
+            window_msg_id: None,
+            h_icon: HICON::NULL,
+            tooltip: None,
+            shown: false,
+        }
+    }
+
+    pub fn id(mut self, id: u16) -> Self {
+        //! See [`TrayIcon::with_id()`].
+
+        self.id = Some(id);
+        self
+    }
+
+    pub fn guid(mut self, guid: GUID) -> Self {
+        //! See [`TrayIcon::with_guid()`].
+
+        self.guid = Some(guid);
+        self
+    }
+
+    pub fn window_msg_id(mut self, window_msg_id: u32) -> Self {
+        self.window_msg_id = Some(window_msg_id);
+        self
+    }
+
+    pub unsafe fn icon(mut self, h_icon: HICON) -> Self {
+        //! # Safety
+        //! See [`TrayIcon::set_icon()`].
+
+        self.h_icon = h_icon;
+        self
+    }
+
+    pub fn tooltip<T>(mut self, tooltip: T) -> Self
+    where
+        T: Into<HSTRING>,
+    {
+        //! See [`TrayIcon::set_tooltip()`].
+
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn shown(mut self, shown: bool) -> Self {
+        self.shown = shown;
+        self
+    }
+
+    pub fn build(self) -> windows::core::Result<TrayIcon> {
+        //! Adds the icon to the tray.
+
+        TrayIcon::from_builder(self)
+    }
+}
+
+/// Multiple [`TrayIcon`]s with IDs, sharing one window and window message ID.
+///
+/// The icons are deleted from the tray when they're removed from the set or the set is dropped.
+pub struct TrayIconSet {
+    hwnd: HWND,
+    window_msg_id: Option<u32>,
+    icons: HashMap<u16, TrayIcon>,
+}
+
+impl TrayIconSet {
+    pub fn new(hwnd: HWND, window_msg_id: Option<u32>) -> Self {
+        Self {
+            hwnd,
+            window_msg_id,
+            icons: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, id: u16) -> windows::core::Result<&mut TrayIcon> {
+        //! Adds a new tray icon with the ID. If an icon with the ID already exists, it's replaced.
+
+        self.icons.remove(&id);
+        let tray_icon = TrayIcon::with_id(id, self.hwnd, self.window_msg_id)?;
+
+        Ok(self.icons.entry(id).or_insert(tray_icon))
+    }
+
+    pub fn get_mut(&mut self, id: u16) -> Option<&mut TrayIcon> {
+        self.icons.get_mut(&id)
+    }
+
+    pub fn remove(&mut self, id: u16) -> Option<TrayIcon> {
+        //! Removes the icon from the set. Dropping the returned value deletes it from the tray.
+
+        self.icons.remove(&id)
+    }
+
+    pub fn readd(&self) -> windows::core::Result<()> {
+        //! Calls [`TrayIcon::readd()`] for all icons.
+
+        self.icons.values().try_for_each(TrayIcon::readd)
+    }
+
+    pub fn handle_taskbar_created(&self, msg_id: u32) -> windows::core::Result<bool> {
+        //! Like [`TrayIcon::handle_taskbar_created()`], for all icons.
+
+        if is_taskbar_created_msg(msg_id) {
+            self.readd()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    pub fn dispatch(&mut self, wparam: WPARAM, lparam: LPARAM) -> (u16, SimplifiedTrayIconMsg) {
+        //! Translates a window message with the set's window message ID by means of [`TrayIcon::simplifying_translate_window_msg()`] of the icon it's meant for. Returns the icon ID along with the message.
+
+        let icon_id = translate_window_msg(wparam, lparam).icon_id;
+
+        let msg = match self.icons.get_mut(&icon_id) {
+            Some(tray_icon) => tray_icon.simplifying_translate_window_msg(wparam, lparam),
+            None => SimplifiedTrayIconMsg::Other(translate_window_msg(wparam, lparam)),
+        };
+
+        (icon_id, msg)
+    }
+}
+
+/// Options for [`TrayIcon::show_balloon_with()`].
+pub struct BalloonOptions {
+    pub icon: BalloonIcon,
+    pub title: Option<HSTRING>,
+    pub text: HSTRING,
+    /// Whether to discard the notification, if it can't be shown immediately (`NIF_REALTIME`).
+    pub realtime_only: bool,
+    /// Whether to show the notification even during quiet time, i.e., not setting `NIIF_RESPECT_QUIET_TIME`.
+    pub override_quiet_time: bool,
+    /// Default: `true`.
+    pub allow_sound: bool,
+    /// See [`TrayIcon::set_balloon_uses_large_icon()`].
+    pub large_icon: bool,
+}
+
+impl Default for BalloonOptions {
+    fn default() -> Self {
+        Self {
+            icon: BalloonIcon::None,
+            title: None,
+            text: HSTRING::new(),
+            realtime_only: false,
+            override_quiet_time: false,
+            allow_sound: true,
+            large_icon: false,
+        }
+    }
+}
+
 pub enum BalloonIcon {
     None,
     Info,
@@ -423,10 +764,30 @@ pub enum SimplifiedTrayIconMsg {
         x: i16,
         y: i16,
     },
+    /// The balloon notification was clicked.
+    BalloonClicked,
+    /// The balloon notification was closed by the user or disappeared after its timeout.
+    BalloonTimedOut,
+    BalloonShown,
+    /// The balloon notification disappeared for another reason, e.g., because the icon was deleted.
+    BalloonHidden,
     Other(TrayIconMsg),
 }
 
+fn is_taskbar_created_msg(msg_id: u32) -> bool {
+    msg_id != 0 && msg_id == TrayIcon::taskbar_created_message_id()
+}
+
 pub fn translate_window_msg(wparam: WPARAM, lparam: LPARAM) -> TrayIconMsg {
+    //! Translates the parameters of the window message that [`TrayIcon`] sends, as laid out for `NOTIFYICON_VERSION_4`, which the struct always sets:
+    //!
+    //! - `lparam`: Low word is the notification event (like `NIN_SELECT` or `WM_CONTEXTMENU`), high word the icon ID.
+    //! - `wparam`: Low word is the X, high word the Y anchor coordinate in screen space, for `NIN_POPUPOPEN`, `NIN_SELECT`, `NIN_KEYSELECT`, `WM_CONTEXTMENU` and the mouse messages between `WM_MOUSEFIRST` and `WM_MOUSELAST`. Meaningless for other events.
+    //!
+    //! In contrast to a real `WM_CONTEXTMENU` message, the coordinates don't come in `lparam`.
+    //!
+    //! See <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw> (`uCallbackMessage`).
+
     TrayIconMsg {
         msg_id: lparam.low_u16() as _, // `u32` makes comparisons nicer.
         icon_id: lparam.high_u16(),
@@ -438,6 +799,123 @@ pub fn translate_window_msg(wparam: WPARAM, lparam: LPARAM) -> TrayIconMsg {
 pub struct TrayIconMsg {
     pub msg_id: u32,
     pub icon_id: u16,
+    /// Screen coordinate. Only meaningful for some events; see [`translate_window_msg()`].
     pub x: i16,
     pub y: i16,
 }
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{translate_window_msg, SimplifiedTrayIconMsg, TrayIcon, TrayIconSet};
+    use crate::{
+        bit_manipulation::{make_lparam, Width32BitPortion},
+        win32_app::window::{Window, WindowClass},
+        windows, Null, ResGuard,
+    };
+    use windows::Win32::{
+        Foundation::{HINSTANCE, WPARAM},
+        UI::{
+            Shell::{
+                Shell_NotifyIconW, NIF_ICON, NIF_INFO, NIF_SHOWTIP, NIF_STATE, NIF_TIP, NIM_DELETE,
+                NIN_SELECT,
+            },
+            WindowsAndMessaging::{LoadIconW, IDI_APPLICATION, WM_CONTEXTMENU},
+        },
+    };
+
+    #[test]
+    fn taskbar_created_message_id() {
+        let msg_id = TrayIcon::taskbar_created_message_id();
+        assert!((0xc000..=0xffff).contains(&msg_id));
+        assert_eq!(TrayIcon::taskbar_created_message_id(), msg_id);
+    }
+
+    #[test]
+    fn translate_window_msg_version_4_layout() {
+        // Context menu request for icon 3 on a monitor left of the primary one.
+        let msg = translate_window_msg(
+            WPARAM::from_low_high_i16(-200, 1050),
+            make_lparam(WM_CONTEXTMENU as _, 3),
+        );
+        assert_eq!(msg.msg_id, WM_CONTEXTMENU);
+        assert_eq!(msg.icon_id, 3);
+        assert_eq!((msg.x, msg.y), (-200, 1050));
+    }
+
+    #[ignore]
+    #[test]
+    fn interleaved_modifications() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        let h_icon = ResGuard::with_acq_and_destroy_icon(|| unsafe {
+            LoadIconW(HINSTANCE::NULL, IDI_APPLICATION)
+        })?;
+
+        let mut tray_icon = TrayIcon::with_id(1, window.hwnd(), None)?;
+        assert_eq!(tray_icon.notify_icon_data.uFlags, NIF_STATE | NIF_INFO);
+        assert_eq!((tray_icon.id(), tray_icon.guid()), (1, None));
+
+        unsafe { tray_icon.set_icon(*h_icon)? };
+        tray_icon.set_tooltip(Some("Tooltip"))?;
+        assert!(tray_icon.show(true)?);
+        assert!(!tray_icon.show(true)?);
+        assert!(!tray_icon.handle_taskbar_created(WM_CONTEXTMENU)?);
+
+        // Simulate an `explorer.exe` restart, which loses the icon.
+        let _ = unsafe { Shell_NotifyIconW(NIM_DELETE, &tray_icon.notify_icon_data) };
+        assert!(tray_icon.handle_taskbar_created(TrayIcon::taskbar_created_message_id())?);
+        assert_eq!(
+            tray_icon.notify_icon_data.uFlags,
+            NIF_STATE | NIF_INFO | NIF_ICON | NIF_TIP | NIF_SHOWTIP
+        );
+        assert!(tray_icon.is_shown());
+        assert_eq!(tray_icon.tooltip(), "Tooltip");
+
+        tray_icon.set_tooltip(None::<&str>)?;
+        unsafe { tray_icon.set_icon(*h_icon)? };
+        assert_eq!(
+            tray_icon.notify_icon_data.uFlags,
+            NIF_STATE | NIF_INFO | NIF_ICON
+        );
+        assert_eq!(tray_icon.tooltip(), "");
+
+        tray_icon.set_tooltip(Some("Other tooltip"))?;
+        assert!(tray_icon.show(false)?);
+        unsafe { tray_icon.set_icon(*h_icon)? };
+        assert_eq!(
+            tray_icon.notify_icon_data.uFlags,
+            NIF_STATE | NIF_INFO | NIF_ICON | NIF_TIP | NIF_SHOWTIP
+        );
+        assert!(!tray_icon.is_shown());
+
+        tray_icon.delete()?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[test]
+    fn tray_icon_set() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        let mut tray_icons = TrayIconSet::new(window.hwnd(), None);
+        tray_icons.add(1)?;
+        tray_icons.add(2)?.set_tooltip(Some("Second"))?;
+
+        assert!(matches!(
+            tray_icons.dispatch(WPARAM(0), make_lparam(NIN_SELECT as _, 2)),
+            (2, SimplifiedTrayIconMsg::Activated)
+        ));
+
+        assert!(tray_icons.remove(1).is_some());
+        assert!(tray_icons.get_mut(1).is_none());
+        assert!(matches!(
+            tray_icons.dispatch(WPARAM(0), make_lparam(NIN_SELECT as _, 1)),
+            (1, SimplifiedTrayIconMsg::Other(_))
+        ));
+
+        Ok(())
+    }
+}
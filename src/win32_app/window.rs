@@ -1,27 +1,46 @@
 use crate::{
     core::{CheckNullError, CheckNumberError, ResultExt},
-    windows, Null, Zeroed,
+    dual_call, windows, FirstCallExpectation, Null, Zeroed,
+};
+use std::{
+    cell::Cell,
+    ffi::c_void,
+    mem,
+    sync::atomic::{AtomicU64, Ordering},
 };
-use std::{cell::Cell, mem};
 use windows::{
     core::{HSTRING, PCWSTR},
     Win32::{
-        Foundation::{SetLastError, ERROR_SUCCESS, HWND, LPARAM, LRESULT, POINT, SIZE, WPARAM},
+        Foundation::{
+            SetLastError, ERROR_CLASS_ALREADY_EXISTS, ERROR_SUCCESS, HINSTANCE, HWND, LPARAM,
+            LRESULT, POINT, RECT, SIZE, WPARAM,
+        },
+        Graphics::Gdi::{COLOR_WINDOW, HBRUSH},
         System::{LibraryLoader::GetModuleHandleW, Performance::QueryPerformanceCounter},
         UI::WindowsAndMessaging::{
-            CreateWindowExW, DefWindowProcW, DestroyWindow, GetWindowLongPtrW, IsWindow,
-            RegisterClassExW, SetWindowLongPtrW, UnregisterClassW, CW_USEDEFAULT, GWLP_USERDATA,
-            HMENU, HWND_MESSAGE, WINDOW_EX_STYLE, WINDOW_STYLE, WNDCLASSEXW,
+            CreateWindowExW, DefWindowProcW, DestroyWindow, GetWindowLongPtrW, GetWindowRect,
+            GetWindowTextLengthW, GetWindowTextW, IsWindow, LoadCursorW, MoveWindow,
+            RegisterClassExW, SetWindowLongPtrW, SetWindowPos, SetWindowTextW,
+            SystemParametersInfoW, UnregisterClassW, CW_USEDEFAULT, GWLP_USERDATA, HCURSOR, HICON,
+            HMENU, HWND_MESSAGE, IDC_ARROW, SET_WINDOW_POS_FLAGS, SPI_GETWORKAREA, SWP_NOACTIVATE,
+            SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+            WINDOW_EX_STYLE, WINDOW_STYLE, WNDCLASSEXW, WNDCLASS_STYLES,
         },
     },
 };
 
+#[cfg(feature = "f_Win32_UI_Shell")]
+mod subclass;
 mod translate;
 
+#[cfg(feature = "f_Win32_UI_Shell")]
+pub use subclass::*;
 pub use translate::*;
 
 thread_local! {
     static NEXT_WINDOW_USER_DATA_ON_INIT: Cell<isize> = const { Cell::new(0) };
+    /// The error that made the window procedure abort the window creation, to be returned instead of the less specific error of `CreateWindowExW()`.
+    static WINDOW_CREATION_ERROR: Cell<Option<windows::core::Error>> = const { Cell::new(None) };
 }
 
 // For trait bounds in this API.
@@ -36,6 +55,7 @@ impl<F> WndProc for F where F: FnMut(HWND, u32, WPARAM, LPARAM) -> Option<LRESUL
 /// - Don't use `Get...`/`SetWindowLongPtrW(...GWLP_USERDATA...)` on a window created from an instance of this struct, because it stores internal data necessary for the struct to function.
 pub struct WindowClass<'a> {
     atom: u16,
+    h_instance: HINSTANCE,
     /// Double-`Box`, converted with `Box::into_raw()` (to get thin pointer).
     wnd_proc_ptr: *mut Box<dyn WndProc + 'a>,
 }
@@ -67,11 +87,30 @@ impl<'a> WindowClass<'a> {
     where
         F: WndProc + 'a,
     {
+        Self::with_instance(
+            unsafe { GetModuleHandleW(PCWSTR::NULL)? }.into(),
+            name,
+            wnd_proc,
+        )
+    }
+
+    pub fn with_instance<F>(
+        h_instance: HINSTANCE,
+        name: &str,
+        wnd_proc: F,
+    ) -> windows::core::Result<Self>
+    where
+        F: WndProc + 'a,
+    {
+        //! Registers the class for the module, instead of for the executable. Necessary in a DLL, whose module handle you can get from `DllMain()` or with `GetModuleHandleExW()` and `GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS`.
+        //!
+        //! Windows created from the class with [`WindowBuilder`] and the class unregistration on drop use the same module handle.
+
         Self::with_details(
             WNDCLASSEXW {
                 cbSize: mem::size_of::<WNDCLASSEXW>() as _,
                 lpfnWndProc: Some(Self::base_wnd_proc),
-                hInstance: unsafe { GetModuleHandleW(PCWSTR::NULL)? }.into(),
+                hInstance: h_instance,
                 lpszClassName: PCWSTR(HSTRING::from(name).as_ptr()),
                 ..Default::default()
             },
@@ -86,12 +125,32 @@ impl<'a> WindowClass<'a> {
     where
         F: WndProc + 'a,
     {
-        //! The `lpfnWndProc` field will be overwritten.
+        //! The `lpfnWndProc` field will be overwritten. A null `hInstance` field is replaced with the module handle of the executable.
+        //!
+        //! If a class with the same name was already registered in the process, the error has the code of `ERROR_CLASS_ALREADY_EXISTS` and a message pointing this out. Names from [`Self::make_name()`] don't collide.
 
         wnd_class_ex.lpfnWndProc = Some(Self::base_wnd_proc);
+        if wnd_class_ex.hInstance.is_null() {
+            wnd_class_ex.hInstance = unsafe { GetModuleHandleW(PCWSTR::NULL)? }.into();
+        }
+
+        let atom = unsafe { RegisterClassExW(&wnd_class_ex) }
+            .nonzero_or_win32_err()
+            .map_err(|e| {
+                if e.code() == ERROR_CLASS_ALREADY_EXISTS.to_hresult() {
+                    let message = "a window class with this name is already registered in the process (consider using `WindowClass::make_name()`)";
+                    #[cfg(feature = "windows_v0_52")]
+                    let message = HSTRING::from(message);
+
+                    windows::core::Error::new(e.code(), message)
+                } else {
+                    e
+                }
+            })?;
 
         Ok(Self {
-            atom: unsafe { RegisterClassExW(&wnd_class_ex) }.nonzero_or_win32_err()?,
+            atom,
+            h_instance: wnd_class_ex.hInstance,
             // Double indirection to get thin pointer.
             wnd_proc_ptr: Box::into_raw(Box::new(Box::new(wnd_proc))),
         })
@@ -99,17 +158,29 @@ impl<'a> WindowClass<'a> {
 
     pub fn make_name() -> windows::core::Result<String> {
         //! Generates a time-based class name.
+        //!
+        //! A process-wide counter is part of the name, so that names made within the same tick of the performance counter are still unique, even across threads.
+
+        static NEXT_CLASS_NAME_COUNTER: AtomicU64 = AtomicU64::new(0);
 
         let mut precise_time = 0;
         unsafe { QueryPerformanceCounter(&mut precise_time)? };
 
-        Ok(format!("unnamed_{precise_time:x}"))
+        let counter = NEXT_CLASS_NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        Ok(format!("unnamed_{precise_time:x}_{counter:x}"))
     }
 
     pub fn atom(&self) -> u16 {
         self.atom
     }
 
+    pub fn h_instance(&self) -> HINSTANCE {
+        //! Returns the module handle the class was registered with.
+
+        self.h_instance
+    }
+
     extern "system" fn base_wnd_proc(
         hwnd: HWND,
         msg_id: u32,
@@ -132,7 +203,9 @@ impl<'a> WindowClass<'a> {
                 SetWindowLongPtrW(hwnd, GWLP_USERDATA, user_data).nonzero_with_win32_or_err()
             });
 
-            if result.is_err() {
+            if let Err(error) = result {
+                set_window_creation_error(error);
+
                 // Make `CreateWindowExW()` fail.
                 // (First message may be `WM_GETMINMAXINFO`, then, `WM_NCCREATE` is expected, which still happens during the `CreateWindowExW()` call. `LRESULT(0)` indicates an error for `WM_NCCREATE`, while it indicates success for `WM_GETMINMAXINFO` and many other messages.)
                 return LRESULT(0);
@@ -155,19 +228,124 @@ impl<'a> WindowClass<'a> {
 impl Drop for WindowClass<'_> {
     fn drop(&mut self) {
         unsafe {
-            if let Ok(h_module) = GetModuleHandleW(PCWSTR::NULL) {
-                let result = UnregisterClassW(PCWSTR(self.atom as _), h_module);
-                debug_assert!(
-                    result.is_ok(),
-                    "couldn't unregister window class (did you adhere to proper drop order?): {result:?}"
-                );
-            }
+            let result = UnregisterClassW(PCWSTR(self.atom as _), self.h_instance);
+            debug_assert!(
+                result.is_ok(),
+                "couldn't unregister window class (did you adhere to proper drop order?): {result:?}"
+            );
 
             drop(Box::from_raw(self.wnd_proc_ptr));
         }
     }
 }
 
+/// Configures a [`WindowClass`] without having to fill a `WNDCLASSEXW` struct.
+///
+/// By default, the class uses the arrow cursor and the `COLOR_WINDOW` background, so that it can be used for a regular overlapped window right away.
+pub struct WindowClassBuilder {
+    class_name: Option<String>,
+    h_instance: Option<HINSTANCE>,
+    style: WNDCLASS_STYLES,
+    cursor: Option<HCURSOR>,
+    background: HBRUSH,
+    icon: HICON,
+    small_icon: HICON,
+}
+
+impl WindowClassBuilder {
+    pub fn new() -> Self {
+        Self {
+            class_name: None,
+            h_instance: None,
+            style: WNDCLASS_STYLES(0),
+            cursor: None,
+            background: HBRUSH((COLOR_WINDOW.0 + 1) as _), // As documented for `hbrBackground`.
+            icon: HICON::NULL,
+            small_icon: HICON::NULL,
+        }
+    }
+
+    pub fn class_name(mut self, class_name: &str) -> Self {
+        //! If not set, [`WindowClass::make_name()`] is used.
+
+        self.class_name = Some(class_name.to_owned());
+        self
+    }
+
+    pub fn h_instance(mut self, h_instance: HINSTANCE) -> Self {
+        //! If not set, the module handle of the executable is used. See [`WindowClass::with_instance()`].
+
+        self.h_instance = Some(h_instance);
+        self
+    }
+
+    pub fn style(mut self, style: WNDCLASS_STYLES) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn cursor(mut self, cursor: HCURSOR) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn background(mut self, background: HBRUSH) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn icon(mut self, icon: HICON) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn small_icon(mut self, small_icon: HICON) -> Self {
+        self.small_icon = small_icon;
+        self
+    }
+
+    pub fn build<'a, F>(self, wnd_proc: F) -> windows::core::Result<WindowClass<'a>>
+    where
+        F: WndProc + 'a,
+    {
+        //! Registers the class. See [`WindowClass::new()`] regarding the window procedure.
+
+        let class_name = HSTRING::from(match self.class_name {
+            Some(class_name) => class_name,
+            None => WindowClass::make_name()?,
+        });
+
+        let cursor = match self.cursor {
+            Some(cursor) => cursor,
+            None => unsafe { LoadCursorW(HINSTANCE::NULL, IDC_ARROW)? },
+        };
+
+        WindowClass::with_details(
+            WNDCLASSEXW {
+                cbSize: mem::size_of::<WNDCLASSEXW>() as _,
+                style: self.style,
+                hInstance: match self.h_instance {
+                    Some(h_instance) => h_instance,
+                    None => unsafe { GetModuleHandleW(PCWSTR::NULL)? }.into(),
+                },
+                hIcon: self.icon,
+                hCursor: cursor,
+                hbrBackground: self.background,
+                lpszClassName: PCWSTR(class_name.as_ptr()),
+                hIconSm: self.small_icon,
+                ..Default::default()
+            },
+            wnd_proc,
+        )
+    }
+}
+
+impl Default for WindowClassBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A window created with a [`WindowClass`].
 ///
 /// The first calls of the window procedure are made during the constructor call; then during the message loop.
@@ -181,29 +359,16 @@ impl Window {
         //!
         //! See <https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows>.
 
-        Self::with_details(
-            class,
-            Some(HWND_MESSAGE),
-            WINDOW_STYLE(0),
-            None,
-            None,
-            None,
-            None,
-        )
+        WindowBuilder::new(class).parent(HWND_MESSAGE).create()
     }
 
     pub fn new_invisible(class: &WindowClass) -> windows::core::Result<Self> {
         //! Meant for windows that stay invisible. Necessary instead of a message-only window, if you want to receive broadcast messages like `WM_ENDSESSION` or `RegisterWindowMessageW(w!("TaskbarCreated"))`.
 
-        Self::with_details(
-            class,
-            None,
-            WINDOW_STYLE(0),
-            None,
-            Some((POINT::zeroed(), SIZE::zeroed())),
-            None,
-            None,
-        )
+        WindowBuilder::new(class)
+            .position(POINT::zeroed())
+            .size(SIZE::zeroed())
+            .create()
     }
 
     pub fn with_details(
@@ -215,62 +380,299 @@ impl Window {
         text: Option<PCWSTR>,
         menu: Option<HMENU>,
     ) -> windows::core::Result<Self> {
-        //! Creates a window with `CreateWindowExW()`.
+        //! Creates a window with `CreateWindowExW()`. See [`WindowBuilder`] for a more readable alternative.
         //!
         //! `None` for `placement` uses `CW_USEDEFAULT` for all four values.
 
+        WindowBuilder {
+            class,
+            parent,
+            style,
+            ex_style: ex_style.unwrap_or(WINDOW_EX_STYLE(0)),
+            position: placement.map(|(pos, _)| pos),
+            size: placement.map(|(_, size)| size),
+            text: None,
+            menu,
+            create_param: None,
+        }
+        .create_with_text(text.unwrap_or(PCWSTR::NULL))
+    }
+
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+
+    pub fn is_valid(&self) -> bool {
+        //! Returns whether the associated `HWND` is still valid.
+        //!
+        //! It isn't valid anymore, if [`DestroyWindow()`][1] was called.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroywindow
+
+        unsafe { IsWindow(self.hwnd) }.as_bool()
+    }
+
+    pub fn text(&self) -> windows::core::Result<String> {
+        //! Returns the window text (title bar text, or control text) with [`GetWindowTextW()`][1].
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindowtextw
+
+        let mut buffer = Vec::new();
+        let mut len = 0;
+
+        dual_call(FirstCallExpectation::Ok, |getting_buffer_size| {
+            // Zero can also mean an empty text.
+            unsafe { SetLastError(ERROR_SUCCESS) };
+
+            len = if getting_buffer_size {
+                unsafe { GetWindowTextLengthW(self.hwnd) }
+            } else {
+                buffer.resize(len as usize + 1 /*null-termination*/, 0);
+                unsafe { GetWindowTextW(self.hwnd, &mut buffer) }
+            }
+            .nonzero_with_win32_or_err()?;
+
+            Ok(())
+        })?;
+
+        Ok(String::from_utf16(&buffer[..len as _])?)
+    }
+
+    pub fn set_text<T>(&self, text: T) -> windows::core::Result<()>
+    where
+        T: Into<HSTRING>,
+    {
+        //! Sets the window text with [`SetWindowTextW()`][1].
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowtextw
+
+        unsafe { SetWindowTextW(self.hwnd, &text.into()) }
+    }
+
+    pub fn move_resize(&self, pos: POINT, size: SIZE) -> windows::core::Result<()> {
+        //! Moves and resizes the window with [`MoveWindow()`][1] and repaints it.
+        //!
+        //! The window procedure is called synchronously during the call (e.g., with `WM_SIZE`). If you call this from the window procedure, see [`crate::ReentrantRefCell`].
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-movewindow
+
+        unsafe { MoveWindow(self.hwnd, pos.x, pos.y, size.cx, size.cy, true) }
+    }
+
+    pub fn set_z_order(
+        &self,
+        insert_after: HWND,
+        flags: SET_WINDOW_POS_FLAGS,
+    ) -> windows::core::Result<()> {
+        //! Changes the window's position in the Z order with [`SetWindowPos()`][1], keeping its position and size. `SWP_NOMOVE | SWP_NOSIZE` are added to `flags`.
+        //!
+        //! `insert_after` can also be one of the special values like `HWND_TOP`. As with [`Self::move_resize()`], the window procedure is called synchronously.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos
+
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                insert_after,
+                0,
+                0,
+                0,
+                0,
+                flags | SWP_NOMOVE | SWP_NOSIZE,
+            )
+        }
+    }
+
+    pub fn center_on_work_area(&self) -> windows::core::Result<()> {
+        //! Centers the window on the work area of the primary monitor (the screen without the taskbar), as retrieved with [`SystemParametersInfoW()`][1] and `SPI_GETWORKAREA`.
+        //!
+        //! As with [`Self::move_resize()`], the window procedure is called synchronously.
+        //!
+        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfow
+
+        let mut work_area = RECT::default();
+        unsafe {
+            SystemParametersInfoW(
+                SPI_GETWORKAREA,
+                0,
+                Some(&mut work_area as *mut _ as _),
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )
+        }?;
+
+        self.center_on_rect(&work_area)
+    }
+
+    pub fn center_on(&self, other: HWND) -> windows::core::Result<()> {
+        //! Centers the window on another window, like its parent or owner.
+        //!
+        //! As with [`Self::move_resize()`], the window procedure is called synchronously.
+
+        let mut other_rect = RECT::default();
+        unsafe { GetWindowRect(other, &mut other_rect) }?;
+
+        self.center_on_rect(&other_rect)
+    }
+
+    fn center_on_rect(&self, rect: &RECT) -> windows::core::Result<()> {
+        let mut window_rect = RECT::default();
+        unsafe { GetWindowRect(self.hwnd, &mut window_rect) }?;
+
+        let width = window_rect.right - window_rect.left;
+        let height = window_rect.bottom - window_rect.top;
+
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                HWND::NULL,
+                rect.left + (rect.right - rect.left - width) / 2,
+                rect.top + (rect.bottom - rect.top - height) / 2,
+                0,
+                0,
+                SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            )
+        }
+    }
+}
+
+/// Configures a [`Window`] to be created with `CreateWindowExW()`.
+///
+/// Without further configuration, a top-level window without styles, with default position and size is created.
+pub struct WindowBuilder<'c, 'a> {
+    class: &'c WindowClass<'a>,
+    parent: Option<HWND>,
+    style: WINDOW_STYLE,
+    ex_style: WINDOW_EX_STYLE,
+    position: Option<POINT>,
+    size: Option<SIZE>,
+    text: Option<HSTRING>,
+    menu: Option<HMENU>,
+    create_param: Option<*mut c_void>,
+}
+
+impl<'c, 'a> WindowBuilder<'c, 'a> {
+    pub fn new(class: &'c WindowClass<'a>) -> Self {
+        Self {
+            class,
+            parent: None,
+            style: WINDOW_STYLE(0),
+            ex_style: WINDOW_EX_STYLE(0),
+            position: None,
+            size: None,
+            text: None,
+            menu: None,
+            create_param: None,
+        }
+    }
+
+    pub fn parent(mut self, parent: HWND) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub fn style(mut self, style: WINDOW_STYLE) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn ex_style(mut self, ex_style: WINDOW_EX_STYLE) -> Self {
+        self.ex_style = ex_style;
+        self
+    }
+
+    pub fn position(mut self, position: POINT) -> Self {
+        //! If not set, `CW_USEDEFAULT` is used.
+
+        self.position = Some(position);
+        self
+    }
+
+    pub fn size(mut self, size: SIZE) -> Self {
+        //! If not set, `CW_USEDEFAULT` is used.
+
+        self.size = Some(size);
+        self
+    }
+
+    pub fn text<T>(mut self, text: T) -> Self
+    where
+        T: Into<HSTRING>,
+    {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn menu(mut self, menu: HMENU) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    pub fn create_param(mut self, create_param: *mut c_void) -> Self {
+        //! Sets the value passed as the last parameter of `CreateWindowExW()`. The window procedure receives it as `lpCreateParams` of the `CREATESTRUCTW` on `WM_NCCREATE` and `WM_CREATE`, which you can get with [`translate_create_msg()`].
+        //!
+        //! The pointer isn't used by this crate in any way. If the procedure dereferences it, the pointee must stay valid during the [`Self::create()`] call. If the procedure stores the pointer for later use, the pointee must outlive the window.
+
+        self.create_param = Some(create_param);
+        self
+    }
+
+    pub fn create(&self) -> windows::core::Result<Window> {
+        self.create_with_text(
+            self.text
+                .as_ref()
+                .map_or(PCWSTR::NULL, |text| PCWSTR(text.as_ptr())),
+        )
+    }
+
+    fn create_with_text(&self, text: PCWSTR) -> windows::core::Result<Window> {
         // Pass window procedure via thread-local storage instead of `CREATESTRUCTW`, because `WM_GETMINMAXINFO` can be sent before `WM_NCCREATE`.
-        NEXT_WINDOW_USER_DATA_ON_INIT.set(class.wnd_proc_ptr as _);
+        NEXT_WINDOW_USER_DATA_ON_INIT.set(self.class.wnd_proc_ptr as _);
+        WINDOW_CREATION_ERROR.set(None);
 
         // Create window.
-        let (pos, size) = placement.unwrap_or((
-            POINT {
-                x: CW_USEDEFAULT,
-                y: CW_USEDEFAULT,
-            },
-            SIZE {
-                cx: CW_USEDEFAULT,
-                cy: CW_USEDEFAULT,
-            },
-        ));
+        let pos = self.position.unwrap_or(POINT {
+            x: CW_USEDEFAULT,
+            y: CW_USEDEFAULT,
+        });
+        let size = self.size.unwrap_or(SIZE {
+            cx: CW_USEDEFAULT,
+            cy: CW_USEDEFAULT,
+        });
 
         let hwnd = unsafe {
             CreateWindowExW(
-                ex_style.unwrap_or(WINDOW_EX_STYLE(0)),
-                PCWSTR(class.atom as _),
-                text.unwrap_or(PCWSTR::NULL),
-                style,
+                self.ex_style,
+                PCWSTR(self.class.atom as _),
+                text,
+                self.style,
                 pos.x,
                 pos.y,
                 size.cx,
                 size.cy,
-                parent.unwrap_or(HWND::NULL),
-                menu.unwrap_or(HMENU::NULL),
-                GetModuleHandleW(PCWSTR::NULL)?,
-                None,
+                self.parent.unwrap_or(HWND::NULL),
+                self.menu.unwrap_or(HMENU::NULL),
+                self.class.h_instance,
+                self.create_param
+                    .map(|create_param| create_param as *const c_void),
             )
         };
         #[cfg(any(feature = "windows_v0_48", feature = "windows_v0_52"))]
-        let hwnd = hwnd.nonnull_or_e_handle()?; // Checking `GetLastError()` would be better.
-        #[cfg(not(any(feature = "windows_v0_48", feature = "windows_v0_52")))]
-        let hwnd = hwnd?;
-
-        Ok(Self { hwnd })
-    }
+        let hwnd = hwnd.nonnull_or_e_handle(); // Checking `GetLastError()` would be better.
 
-    pub fn hwnd(&self) -> HWND {
-        self.hwnd
+        Ok(Window {
+            hwnd: hwnd.map_err(take_window_creation_error)?,
+        })
     }
+}
 
-    pub fn is_valid(&self) -> bool {
-        //! Returns whether the associated `HWND` is still valid.
-        //!
-        //! It isn't valid anymore, if [`DestroyWindow()`][1] was called.
-        //!
-        //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroywindow
+fn set_window_creation_error(error: windows::core::Error) {
+    // Keep the first error, in case the window procedure fails on multiple messages.
+    let first_error = WINDOW_CREATION_ERROR.take().unwrap_or(error);
+    WINDOW_CREATION_ERROR.set(Some(first_error));
+}
 
-        unsafe { IsWindow(self.hwnd) }.as_bool()
-    }
+fn take_window_creation_error(fallback: windows::core::Error) -> windows::core::Error {
+    WINDOW_CREATION_ERROR.take().unwrap_or(fallback)
 }
 
 impl Drop for Window {
@@ -282,16 +684,30 @@ impl Drop for Window {
 
 #[cfg(all(test, feature = "windows_latest_compatible_all"))]
 mod tests {
-    use super::{Window, WindowClass};
-    use crate::{foundation::LParamExt, win32_app::msg_loop, windows, Null};
-    use std::{cell::RefCell, rc::Rc};
+    use super::{
+        set_window_creation_error, take_window_creation_error, translate_create_msg, Window,
+        WindowBuilder, WindowClass, WindowClassBuilder,
+    };
+    use crate::{
+        foundation::LParamExt, win32_app::msg_loop, windows, wnds_and_msging::message_name, Null,
+    };
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
     use windows::{
         core::{w, HSTRING, PCWSTR},
         Win32::{
-            Foundation::{HWND, LRESULT, POINT, SIZE},
+            Foundation::{
+                ERROR_ACCESS_DENIED, ERROR_CLASS_ALREADY_EXISTS, ERROR_INVALID_WINDOW_HANDLE,
+                E_HANDLE, HINSTANCE, HWND, LRESULT, POINT, RECT, SIZE,
+            },
+            System::LibraryLoader::GetModuleHandleW,
             UI::WindowsAndMessaging::{
-                MessageBoxW, PostQuitMessage, MB_OK, MINMAXINFO, WM_DESTROY, WM_GETMINMAXINFO,
-                WM_LBUTTONUP, WS_OVERLAPPEDWINDOW, WS_VISIBLE,
+                GetWindowLongPtrW, GetWindowRect, MessageBoxW, PostQuitMessage, CS_HREDRAW,
+                CS_VREDRAW, GWLP_HINSTANCE, HWND_BOTTOM, MB_OK, MINMAXINFO, SWP_NOACTIVATE,
+                WM_DESTROY, WM_GETMINMAXINFO, WM_LBUTTONUP, WM_NCCREATE, WS_OVERLAPPEDWINDOW,
+                WS_VISIBLE,
             },
         },
     };
@@ -302,7 +718,10 @@ mod tests {
         let counter = Rc::new(RefCell::new(1));
 
         let class = WindowClass::new(|hwnd, msg_id, wparam, mut lparam| {
-            println!("window msg received: {hwnd:?}, msg 0x{msg_id:04x}, {wparam:?}, {lparam:?}");
+            println!(
+                "window msg received: {hwnd:?}, msg 0x{msg_id:04x} ({}), {wparam:?}, {lparam:?}",
+                message_name(msg_id).unwrap_or("?")
+            );
 
             match msg_id {
                 WM_LBUTTONUP => {
@@ -335,15 +754,12 @@ mod tests {
 
         *counter.borrow_mut() += 1;
 
-        let _window = Window::with_details(
-            &class,
-            None,
-            WS_OVERLAPPEDWINDOW | WS_VISIBLE,
-            None,
-            Some((POINT { x: 100, y: 100 }, SIZE { cx: 500, cy: 500 })),
-            Some(PCWSTR(HSTRING::from("Test Window").as_ptr())),
-            None,
-        )?;
+        let _window = WindowBuilder::new(&class)
+            .style(WS_OVERLAPPEDWINDOW | WS_VISIBLE)
+            .position(POINT { x: 100, y: 100 })
+            .size(SIZE { cx: 500, cy: 500 })
+            .text("Test Window")
+            .create()?;
 
         *counter.borrow_mut() += 1;
 
@@ -351,4 +767,178 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn window_class_builder() -> windows::core::Result<()> {
+        let class = WindowClassBuilder::new()
+            .class_name(&WindowClass::make_name()?)
+            .style(CS_HREDRAW | CS_VREDRAW)
+            .build(|_, _, _, _| None)?;
+        assert_ne!(class.atom(), 0);
+
+        let window = Window::new_invisible(&class)?;
+        assert!(window.is_valid());
+
+        Ok(())
+    }
+
+    #[test]
+    fn text() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = WindowBuilder::new(&class).text("Initial").create()?;
+        assert_eq!(window.text()?, "Initial");
+
+        window.set_text("Ünïcödé 🦀")?;
+        assert_eq!(window.text()?, "Ünïcödé 🦀");
+
+        window.set_text("")?;
+        assert_eq!(window.text()?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_resize_and_set_z_order() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+
+        window.move_resize(POINT { x: 10, y: 20 }, SIZE { cx: 300, cy: 200 })?;
+        window.set_z_order(HWND_BOTTOM, SWP_NOACTIVATE)?;
+
+        let mut rect = RECT::default();
+        unsafe { GetWindowRect(window.hwnd(), &mut rect)? };
+        assert_eq!(
+            (rect.left, rect.top, rect.right, rect.bottom),
+            (10, 20, 310, 220)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn center_on() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let other = Window::new_invisible(&class)?;
+        let window = Window::new_invisible(&class)?;
+
+        other.move_resize(POINT { x: 100, y: 100 }, SIZE { cx: 400, cy: 300 })?;
+        window.move_resize(POINT { x: 0, y: 0 }, SIZE { cx: 200, cy: 100 })?;
+        window.center_on(other.hwnd())?;
+
+        let mut rect = RECT::default();
+        unsafe { GetWindowRect(window.hwnd(), &mut rect)? };
+        assert_eq!(
+            (rect.left, rect.top, rect.right, rect.bottom),
+            (200, 200, 400, 300)
+        );
+
+        window.center_on_work_area()?;
+        unsafe { GetWindowRect(window.hwnd(), &mut rect)? };
+        assert_eq!((rect.right - rect.left, rect.bottom - rect.top), (200, 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_name_in_tight_loop() -> windows::core::Result<()> {
+        let classes = (0..100)
+            .map(|_| WindowClass::new(|_, _, _, _| None))
+            .collect::<windows::core::Result<Vec<_>>>()?;
+        assert_eq!(classes.len(), 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_name_across_threads() -> windows::core::Result<()> {
+        let threads = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    (0..100)
+                        .map(|_| WindowClass::make_name())
+                        .collect::<windows::core::Result<Vec<_>>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut names = std::collections::HashSet::new();
+        for thread in threads {
+            names.extend(thread.join().unwrap()?);
+        }
+        assert_eq!(names.len(), 400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_name_already_registered() -> windows::core::Result<()> {
+        let name = WindowClass::make_name()?;
+        let _class = WindowClass::with_name(&name, |_, _, _, _| None)?;
+
+        let error = WindowClass::with_name(&name, |_, _, _, _| None)
+            .err()
+            .expect("registration should fail");
+        assert_eq!(error.code(), ERROR_CLASS_ALREADY_EXISTS.to_hresult());
+        assert!(error.message().to_string().contains("already registered"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_instance() -> windows::core::Result<()> {
+        let h_instance: HINSTANCE = unsafe { GetModuleHandleW(w!("user32.dll"))? }.into();
+
+        // Class names are unique per module.
+        let name = WindowClass::make_name()?;
+        let _exe_class = WindowClass::with_name(&name, |_, _, _, _| None)?;
+        let class = WindowClass::with_instance(h_instance, &name, |_, _, _, _| None)?;
+        assert_eq!(class.h_instance(), h_instance);
+
+        let window = Window::new_invisible(&class)?;
+        assert_eq!(
+            unsafe { GetWindowLongPtrW(window.hwnd(), GWLP_HINSTANCE) },
+            h_instance.0 as _
+        );
+
+        // Unregistering with the same module handle is checked by a debug assertion.
+        drop(window);
+        drop(class);
+
+        Ok(())
+    }
+
+    #[test]
+    fn window_creation_error() {
+        // Forcing `SetWindowLongPtrW()` to fail isn't feasible, so the path is exercised without a window.
+        assert_eq!(take_window_creation_error(E_HANDLE.into()), E_HANDLE.into());
+
+        set_window_creation_error(ERROR_ACCESS_DENIED.into());
+        set_window_creation_error(ERROR_INVALID_WINDOW_HANDLE.into());
+        assert_eq!(
+            take_window_creation_error(E_HANDLE.into()),
+            ERROR_ACCESS_DENIED.into()
+        );
+        assert_eq!(take_window_creation_error(E_HANDLE.into()), E_HANDLE.into());
+    }
+
+    #[test]
+    fn create_param() -> windows::core::Result<()> {
+        let received = Cell::new(0);
+        let class = WindowClass::new(|_, msg_id, _, lparam| {
+            if msg_id == WM_NCCREATE {
+                let create_struct = unsafe { translate_create_msg(&lparam) };
+                received.set(unsafe { *(create_struct.lpCreateParams as *const i32) });
+            }
+
+            None
+        })?;
+
+        let mut param = 42_i32;
+        let _window = WindowBuilder::new(&class)
+            .create_param(&mut param as *mut i32 as _)
+            .create()?;
+        assert_eq!(received.get(), 42);
+
+        Ok(())
+    }
 }
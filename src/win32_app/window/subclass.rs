@@ -0,0 +1,128 @@
+use super::WndProc;
+use crate::{foundation::BoolExt, windows};
+use windows::Win32::{
+    Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+    UI::{
+        Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass},
+        WindowsAndMessaging::WM_NCDESTROY,
+    },
+};
+
+/// A window procedure closure installed on an existing window with `SetWindowSubclass()`.
+///
+/// In contrast to [`super::WindowClass`], this doesn't use `GWLP_USERDATA`, so it also works with windows you didn't create, like common controls. The closure is stored via the subclass reference data. Messages the closure returns `None` for are passed on with `DefSubclassProc()`.
+///
+/// The subclass is removed when the struct is dropped, or when the window is destroyed before that.
+pub struct Subclass<'a> {
+    hwnd: HWND,
+    /// Double-`Box`, converted with `Box::into_raw()` (to get thin pointer). Also used as subclass ID.
+    wnd_proc_ptr: *mut Box<dyn WndProc + 'a>,
+}
+
+impl<'a> Subclass<'a> {
+    pub fn new<F>(hwnd: HWND, wnd_proc: F) -> windows::core::Result<Self>
+    where
+        F: WndProc + 'a,
+    {
+        //! Installs the window procedure on the window. See [`super::WindowClass::new()`] regarding the procedure.
+        //!
+        //! Must be called from the thread the window belongs to. Multiple subclasses can be installed on the same window; the one installed last is called first.
+
+        // Double indirection to get thin pointer.
+        let wnd_proc_ptr: *mut Box<dyn WndProc + 'a> = Box::into_raw(Box::new(Box::new(wnd_proc)));
+
+        let result = unsafe {
+            SetWindowSubclass(
+                hwnd,
+                Some(Self::base_subclass_proc),
+                wnd_proc_ptr as _,
+                wnd_proc_ptr as _,
+            )
+        }
+        .ok_or_e_fail();
+
+        if let Err(error) = result {
+            drop(unsafe { Box::from_raw(wnd_proc_ptr) });
+            return Err(error);
+        }
+
+        Ok(Self { hwnd, wnd_proc_ptr })
+    }
+
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+
+    unsafe extern "system" fn base_subclass_proc(
+        hwnd: HWND,
+        msg_id: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+        subclass_id: usize,
+        ref_data: usize,
+    ) -> LRESULT {
+        // Call window procedure.
+        // (Outer box was dissolved into raw pointer, whose data is simply referenced here. The `Box` you see is the inner `Box`.)
+        let wnd_proc = unsafe { &mut *(ref_data as *mut Box<dyn WndProc>) };
+
+        let lresult = if let Some(lresult) = wnd_proc(hwnd, msg_id, wparam, lparam) {
+            lresult
+        } else {
+            // Call next handler in chain.
+            unsafe { DefSubclassProc(hwnd, msg_id, wparam, lparam) }
+        };
+
+        if msg_id == WM_NCDESTROY {
+            // As recommended by the docs. The closure itself is freed on drop.
+            let _ =
+                unsafe { RemoveWindowSubclass(hwnd, Some(Self::base_subclass_proc), subclass_id) };
+        }
+
+        lresult
+    }
+}
+
+impl Drop for Subclass<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            // Fails if the window was already destroyed, in which case the subclass was removed on `WM_NCDESTROY`.
+            let _ = RemoveWindowSubclass(
+                self.hwnd,
+                Some(Self::base_subclass_proc),
+                self.wnd_proc_ptr as _,
+            );
+
+            drop(Box::from_raw(self.wnd_proc_ptr));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::Subclass;
+    use crate::{
+        win32_app::window::{Window, WindowClass},
+        windows,
+    };
+    use windows::Win32::{
+        Foundation::{LPARAM, LRESULT, WPARAM},
+        UI::WindowsAndMessaging::{SendMessageW, WM_APP},
+    };
+
+    #[test]
+    fn subclass() -> windows::core::Result<()> {
+        let class = WindowClass::new(|_, _, _, _| None)?;
+        let window = Window::new_invisible(&class)?;
+        let send = || unsafe { SendMessageW(window.hwnd(), WM_APP, WPARAM(0), LPARAM(0)) };
+
+        let subclass = Subclass::new(window.hwnd(), |_, msg_id, _, _| {
+            (msg_id == WM_APP).then_some(LRESULT(42))
+        })?;
+        assert_eq!(send(), LRESULT(42));
+
+        drop(subclass);
+        assert_eq!(send(), LRESULT(0));
+
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
-    bit_manipulation::Width32BitPortion, foundation::LParamExt, windows,
+    bit_manipulation::{Width16BitPortion, Width32BitPortion},
+    foundation::LParamExt,
+    windows,
     wnds_and_msging::TimerProcExt,
 };
 use windows::Win32::{
     Foundation::{HWND, LPARAM, WPARAM},
-    UI::WindowsAndMessaging::{PBT_POWERSETTINGCHANGE, TIMERPROC},
+    UI::{
+        Input::KeyboardAndMouse::VIRTUAL_KEY,
+        WindowsAndMessaging::{CREATESTRUCTW, PBT_POWERSETTINGCHANGE, TIMERPROC},
+    },
 };
 
 pub fn translate_command_msg(wparam: WPARAM, lparam: LPARAM) -> CommandMsg {
@@ -37,6 +42,114 @@ pub enum CommandMsg {
     },
 }
 
+/// For `WM_NOTIFY`, sent by common controls like list views, tree views and tooltips. The `NMHDR` is often the first field of a larger, notification-specific struct, to which the `LPARAM` can be cast instead, depending on the `code` field.
+///
+/// See [`translate_notify_code()`] for a few common notification codes.
+///
+/// Activate feature `windows_<version>_f_Win32_UI_Controls`.
+#[cfg(feature = "f_Win32_UI_Controls")]
+pub unsafe fn translate_notify_msg(lparam: &LPARAM) -> &windows::Win32::UI::Controls::NMHDR {
+    lparam.cast_to_ref()
+}
+
+/// Activate feature `windows_<version>_f_Win32_UI_Controls`.
+#[cfg(feature = "f_Win32_UI_Controls")]
+pub fn translate_notify_code(code: u32) -> NotifyCode {
+    use windows::Win32::UI::Controls::{
+        NM_CLICK, NM_DBLCLK, NM_KILLFOCUS, NM_RCLICK, NM_RETURN, NM_SETFOCUS,
+    };
+
+    match code {
+        NM_CLICK => NotifyCode::Click,
+        NM_DBLCLK => NotifyCode::DoubleClick,
+        NM_RCLICK => NotifyCode::RightClick,
+        NM_RETURN => NotifyCode::Return,
+        NM_SETFOCUS => NotifyCode::SetFocus,
+        NM_KILLFOCUS => NotifyCode::KillFocus,
+        code => NotifyCode::Other { code },
+    }
+}
+
+/// The generic `NM_*` notification codes shared by many common controls.
+///
+/// Activate feature `windows_<version>_f_Win32_UI_Controls`.
+#[cfg(feature = "f_Win32_UI_Controls")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotifyCode {
+    Click,
+    DoubleClick,
+    RightClick,
+    Return,
+    SetFocus,
+    KillFocus,
+    /// Like a control-specific code such as `LVN_ITEMCHANGED`.
+    Other {
+        code: u32,
+    },
+}
+
+pub fn translate_size_msg(lparam: LPARAM) -> (u16, u16) {
+    //! For `WM_SIZE`. Returns the new width and height of the client area. (`wparam` contains the type of resizing, like `SIZE_MINIMIZED`.)
+
+    (lparam.low_u16(), lparam.high_u16())
+}
+
+pub fn translate_key_msg(wparam: WPARAM, lparam: LPARAM) -> KeyMsg {
+    //! For `WM_KEYDOWN`, `WM_KEYUP`, `WM_SYSKEYDOWN` and `WM_SYSKEYUP`.
+
+    let flags = lparam.high_u16();
+
+    KeyMsg {
+        virtual_key: VIRTUAL_KEY(wparam.low_u16()),
+        repeat_count: lparam.low_u16(),
+        scan_code: flags.low_u8(),
+        is_extended_key: flags & 0x0100 != 0,
+        is_alt_down: flags & 0x2000 != 0,
+        was_key_down: flags & 0x4000 != 0,
+        is_key_released: flags & 0x8000 != 0,
+    }
+}
+
+/// See <https://learn.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags>.
+#[derive(Debug, PartialEq, Eq)]
+pub struct KeyMsg {
+    pub virtual_key: VIRTUAL_KEY,
+    pub repeat_count: u16,
+    pub scan_code: u8,
+    pub is_extended_key: bool,
+    /// The context code. Always `false` for `WM_KEYDOWN` and `WM_KEYUP`.
+    pub is_alt_down: bool,
+    /// The previous key state. `true` for auto-repeated key-down messages.
+    pub was_key_down: bool,
+    /// The transition state. `true` for key-up messages.
+    pub is_key_released: bool,
+}
+
+pub fn translate_mouse_msg(wparam: WPARAM, lparam: LPARAM) -> MouseMsg {
+    //! For client area mouse messages like `WM_MOUSEMOVE` and `WM_LBUTTONDOWN`. (Not for `WM_MOUSEWHEEL`, whose coordinates are in screen space.)
+
+    MouseMsg {
+        x: lparam.mouse_x(),
+        y: lparam.mouse_y(),
+        modifier_keys: wparam.low_u16(),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MouseMsg {
+    /// Relative to the client area. Can be negative on multi-monitor setups.
+    pub x: i16,
+    pub y: i16,
+    /// `MK_*` flags like `MK_LBUTTON` and `MK_CONTROL`.
+    pub modifier_keys: u16,
+}
+
+pub unsafe fn translate_create_msg(lparam: &LPARAM) -> &CREATESTRUCTW {
+    //! For `WM_NCCREATE` and `WM_CREATE`. The `lpCreateParams` field contains the value set with [`super::WindowBuilder::create_param()`].
+
+    lparam.cast_to_ref()
+}
+
 /// Activate feature `windows_<version>_f_Win32_System_Power`.
 #[cfg(feature = "f_Win32_System_Power")]
 pub unsafe fn translate_power_broadcast_msg(wparam: WPARAM, lparam: &LPARAM) -> PowerBroadcastMsg {
@@ -73,3 +186,101 @@ pub struct TimerMsg {
     pub timer_id: usize,
     pub callback: TIMERPROC,
 }
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{
+        translate_key_msg, translate_mouse_msg, translate_notify_code, translate_notify_msg,
+        translate_size_msg, KeyMsg, MouseMsg, NotifyCode,
+    };
+    use crate::{bit_manipulation::Width32BitPortion, windows};
+    use windows::Win32::{
+        Foundation::{HWND, LPARAM, WPARAM},
+        UI::{
+            Controls::{LVN_ITEMCHANGED, NMHDR, NM_DBLCLK},
+            Input::KeyboardAndMouse::{VK_A, VK_RIGHT},
+        },
+    };
+
+    #[test]
+    fn size_msg() {
+        assert_eq!(
+            translate_size_msg(LPARAM::from_low_high_u16(1920, 1080)),
+            (1920, 1080)
+        );
+    }
+
+    #[test]
+    fn notify_msg() {
+        let nmhdr = NMHDR {
+            hwndFrom: HWND(0x1234 as _),
+            idFrom: 5,
+            code: NM_DBLCLK,
+        };
+        let lparam = LPARAM(&nmhdr as *const NMHDR as _);
+
+        let translated = unsafe { translate_notify_msg(&lparam) };
+        assert_eq!(translated.hwndFrom, nmhdr.hwndFrom);
+        assert_eq!(translated.idFrom, 5);
+        assert_eq!(
+            translate_notify_code(translated.code),
+            NotifyCode::DoubleClick
+        );
+
+        assert_eq!(
+            translate_notify_code(LVN_ITEMCHANGED),
+            NotifyCode::Other {
+                code: LVN_ITEMCHANGED
+            }
+        );
+    }
+
+    #[test]
+    fn key_msg() {
+        // Repeated key-down of "A".
+        assert_eq!(
+            translate_key_msg(WPARAM(VK_A.0 as _), LPARAM(0x401e_0003)),
+            KeyMsg {
+                virtual_key: VK_A,
+                repeat_count: 3,
+                scan_code: 0x1e,
+                is_extended_key: false,
+                is_alt_down: false,
+                was_key_down: true,
+                is_key_released: false,
+            }
+        );
+
+        // Key-up of right arrow key with Alt held down.
+        assert_eq!(
+            translate_key_msg(WPARAM(VK_RIGHT.0 as _), LPARAM(0xe14d_0001_u32 as _)),
+            KeyMsg {
+                virtual_key: VK_RIGHT,
+                repeat_count: 1,
+                scan_code: 0x4d,
+                is_extended_key: true,
+                is_alt_down: true,
+                was_key_down: true,
+                is_key_released: true,
+            }
+        );
+    }
+
+    #[test]
+    fn mouse_msg() {
+        const MK_LBUTTON: u16 = 0x0001;
+        const MK_CONTROL: u16 = 0x0008;
+
+        assert_eq!(
+            translate_mouse_msg(
+                WPARAM((MK_LBUTTON | MK_CONTROL) as _),
+                LPARAM::from_low_high_i16(-10, 200)
+            ),
+            MouseMsg {
+                x: -10,
+                y: 200,
+                modifier_keys: MK_LBUTTON | MK_CONTROL,
+            }
+        );
+    }
+}
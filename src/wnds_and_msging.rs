@@ -2,11 +2,19 @@
 
 use crate::windows;
 use std::mem;
-use windows::Win32::{Foundation::LPARAM, UI::WindowsAndMessaging::TIMERPROC};
+use windows::Win32::{
+    Foundation::{LPARAM, WPARAM},
+    UI::WindowsAndMessaging::{PostThreadMessageW, TIMERPROC, WM_QUIT},
+};
 
 pub trait TimerProcExt {
-    /// It's yet to be confirmed that the transmute works. Create an issue if it works or doesn't.
+    /// Converts the `lParam` of a `WM_TIMER` message, which contains the callback passed to `SetTimer()`, or 0.
+    ///
+    /// (The function pointer and `LPARAM` have the same size on all Windows targets. A round-trip with [`Self::into_lparam()`] is confirmed by a test.)
     unsafe fn from_lparam(lparam: LPARAM) -> Self;
+
+    /// The inverse of [`Self::from_lparam()`].
+    fn into_lparam(self) -> LPARAM;
 }
 
 impl TimerProcExt for TIMERPROC {
@@ -17,4 +25,271 @@ impl TimerProcExt for TIMERPROC {
             None
         }
     }
+
+    fn into_lparam(self) -> LPARAM {
+        LPARAM(self.map_or(0, |timer_proc| timer_proc as usize as _))
+    }
+}
+
+pub fn message_name(msg_id: u32) -> Option<&'static str> {
+    //! Returns the name of a common window message, like `"WM_PAINT"`, for debugging purposes. For unlisted IDs in the ranges reserved for private window classes, applications and `RegisterWindowMessageW()`, a category like `"WM_USER + n"` is returned instead.
+
+    use windows::Win32::UI::WindowsAndMessaging::*;
+
+    crate::core::const_name!(msg_id;
+        WM_NULL,
+        WM_CREATE,
+        WM_DESTROY,
+        WM_MOVE,
+        WM_SIZE,
+        WM_ACTIVATE,
+        WM_SETFOCUS,
+        WM_KILLFOCUS,
+        WM_ENABLE,
+        WM_SETTEXT,
+        WM_GETTEXT,
+        WM_GETTEXTLENGTH,
+        WM_PAINT,
+        WM_CLOSE,
+        WM_QUERYENDSESSION,
+        WM_QUIT,
+        WM_ERASEBKGND,
+        WM_SYSCOLORCHANGE,
+        WM_ENDSESSION,
+        WM_SHOWWINDOW,
+        WM_SETTINGCHANGE,
+        WM_ACTIVATEAPP,
+        WM_TIMECHANGE,
+        WM_CANCELMODE,
+        WM_SETCURSOR,
+        WM_MOUSEACTIVATE,
+        WM_CHILDACTIVATE,
+        WM_GETMINMAXINFO,
+        WM_SETFONT,
+        WM_GETFONT,
+        WM_WINDOWPOSCHANGING,
+        WM_WINDOWPOSCHANGED,
+        WM_COPYDATA,
+        WM_NOTIFY,
+        WM_HELP,
+        WM_CONTEXTMENU,
+        WM_STYLECHANGING,
+        WM_STYLECHANGED,
+        WM_DISPLAYCHANGE,
+        WM_GETICON,
+        WM_SETICON,
+        WM_NCCREATE,
+        WM_NCDESTROY,
+        WM_NCCALCSIZE,
+        WM_NCHITTEST,
+        WM_NCPAINT,
+        WM_NCACTIVATE,
+        WM_GETDLGCODE,
+        WM_NCMOUSEMOVE,
+        WM_NCLBUTTONDOWN,
+        WM_NCLBUTTONUP,
+        WM_INPUT,
+        WM_KEYDOWN,
+        WM_KEYUP,
+        WM_CHAR,
+        WM_DEADCHAR,
+        WM_SYSKEYDOWN,
+        WM_SYSKEYUP,
+        WM_SYSCHAR,
+        WM_UNICHAR,
+        WM_INITDIALOG,
+        WM_COMMAND,
+        WM_SYSCOMMAND,
+        WM_TIMER,
+        WM_HSCROLL,
+        WM_VSCROLL,
+        WM_INITMENU,
+        WM_INITMENUPOPUP,
+        WM_MENUSELECT,
+        WM_ENTERIDLE,
+        WM_UNINITMENUPOPUP,
+        WM_CTLCOLORSTATIC,
+        WM_MOUSEMOVE,
+        WM_LBUTTONDOWN,
+        WM_LBUTTONUP,
+        WM_LBUTTONDBLCLK,
+        WM_RBUTTONDOWN,
+        WM_RBUTTONUP,
+        WM_RBUTTONDBLCLK,
+        WM_MBUTTONDOWN,
+        WM_MBUTTONUP,
+        WM_MBUTTONDBLCLK,
+        WM_MOUSEWHEEL,
+        WM_XBUTTONDOWN,
+        WM_XBUTTONUP,
+        WM_MOUSEHWHEEL,
+        WM_PARENTNOTIFY,
+        WM_ENTERMENULOOP,
+        WM_EXITMENULOOP,
+        WM_SIZING,
+        WM_CAPTURECHANGED,
+        WM_MOVING,
+        WM_POWERBROADCAST,
+        WM_DEVICECHANGE,
+        WM_ENTERSIZEMOVE,
+        WM_EXITSIZEMOVE,
+        WM_DROPFILES,
+        WM_DPICHANGED,
+        WM_GETOBJECT,
+        WM_CUT,
+        WM_COPY,
+        WM_PASTE,
+        WM_CLEAR,
+        WM_UNDO,
+        WM_HOTKEY,
+        WM_PRINT,
+        WM_PRINTCLIENT,
+        WM_THEMECHANGED,
+        WM_CLIPBOARDUPDATE,
+    )
+    .or(match msg_id {
+        // `WM_USER..WM_APP`.
+        0x0400..=0x7fff => Some("WM_USER + n"),
+        // `WM_APP..=0xbfff`.
+        0x8000..=0xbfff => Some("WM_APP + n"),
+        0xc000..=0xffff => Some("registered message"),
+        _ => None,
+    })
+}
+
+pub fn post_thread_message(
+    thread_id: u32,
+    msg_id: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> windows::core::Result<()> {
+    //! Calls [`PostThreadMessageW()`][1]. The message can be received with, e.g., `crate::win32_app::msg_loop::run_till_thread_msg()`.
+    //!
+    //! Fails with `ERROR_INVALID_THREAD_ID`, if the thread doesn't have a message queue yet.
+    //!
+    //! [1]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postthreadmessagew
+
+    #[cfg(feature = "windows_v0_48")]
+    let result = unsafe { PostThreadMessageW(thread_id, msg_id, wparam, lparam) }.ok();
+    #[cfg(not(feature = "windows_v0_48"))]
+    let result = unsafe { PostThreadMessageW(thread_id, msg_id, wparam, lparam) };
+
+    result
+}
+
+pub fn post_quit_with_code(thread_id: u32, exit_code: i32) -> windows::core::Result<()> {
+    //! Posts `WM_QUIT` with the exit code to another thread, making its message loop quit, like `PostQuitMessage()` does for the calling thread, which should be used for the calling thread instead.
+
+    post_thread_message(thread_id, WM_QUIT, WPARAM(exit_code as _), LPARAM(0))
+}
+
+pub fn post_thread_payload<T>(thread_id: u32, msg_id: u32, payload: T) -> windows::core::Result<()>
+where
+    T: Send + 'static,
+{
+    //! Posts a thread message whose `LPARAM` owns the boxed payload. The receiving thread must reclaim it with [`reclaim_thread_payload()`] to free it.
+    //!
+    //! The receiving thread must belong to the calling process. The `LPARAM` is a pointer into this process's heap, which is meaningless in another process.
+    //!
+    //! If posting fails, the payload is dropped right away. If the message is never retrieved, e.g., because the receiving thread exits first, the payload is leaked. The same happens when the receiving thread is in a modal loop at the time, like that of `MessageBoxW()` or `TrackPopupMenu()`, since such loops drop thread messages, which have no window to be dispatched to.
+
+    let ptr = Box::into_raw(Box::new(payload));
+    let result = post_thread_message(thread_id, msg_id, WPARAM(0), LPARAM(ptr as _));
+
+    if result.is_err() {
+        drop(unsafe { Box::from_raw(ptr) });
+    }
+
+    result
+}
+
+pub unsafe fn reclaim_thread_payload<T>(lparam: LPARAM) -> T {
+    //! Takes back ownership of the payload sent with [`post_thread_payload()`].
+    //!
+    //! # Safety
+    //! The `LPARAM` must stem from a message posted with `post_thread_payload::<T>()` from the same process, with the same `T`, and this function must be called exactly once per message. Otherwise, the payload is freed twice, leaked or misinterpreted. Choose a unique message ID per payload type to tell the messages apart.
+
+    *Box::from_raw(lparam.0 as *mut T)
+}
+
+#[cfg(all(test, feature = "windows_latest_compatible_all"))]
+mod tests {
+    use super::{
+        message_name, post_quit_with_code, post_thread_payload, reclaim_thread_payload,
+        TimerProcExt,
+    };
+    use crate::{core::CheckNumberError, windows, Null};
+    use windows::Win32::{
+        Foundation::{HWND, LPARAM},
+        System::Threading::GetCurrentThreadId,
+        UI::WindowsAndMessaging::{
+            GetMessageW, KillTimer, PeekMessageW, SetTimer, MSG, PM_REMOVE, TIMERPROC, WM_APP,
+            WM_CREATE, WM_DESTROY, WM_PAINT, WM_QUIT, WM_TIMER, WM_USER,
+        },
+    };
+
+    #[test]
+    fn timer_proc_from_lparam() -> windows::core::Result<()> {
+        extern "system" fn on_timer(_hwnd: HWND, _msg_id: u32, _event_id: usize, _time: u32) {}
+
+        let timer_id = unsafe {
+            SetTimer(HWND::NULL, 0, 10 /*ms*/, Some(on_timer))
+        }
+        .nonzero_or_win32_err()?;
+
+        // Retrieve, but don't dispatch the message.
+        let mut msg = MSG::default();
+        while msg.message != WM_TIMER {
+            unsafe { GetMessageW(&mut msg, HWND::NULL, WM_TIMER, WM_TIMER) }.ok()?;
+        }
+        unsafe { KillTimer(HWND::NULL, timer_id)? };
+
+        let timer_proc = unsafe { TIMERPROC::from_lparam(msg.lParam) };
+        assert_eq!(
+            timer_proc.map(|timer_proc| timer_proc as usize),
+            Some(on_timer as *const () as usize)
+        );
+        assert_eq!(timer_proc.into_lparam(), msg.lParam);
+
+        assert!(unsafe { TIMERPROC::from_lparam(LPARAM(0)) }.is_none());
+        assert_eq!(None.into_lparam(), LPARAM(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn thread_payload() -> windows::core::Result<()> {
+        let thread_id = unsafe { GetCurrentThreadId() };
+        let mut msg = MSG::default();
+
+        post_thread_payload(thread_id, WM_APP, vec![String::from("payload")])?;
+        assert!(unsafe { PeekMessageW(&mut msg, HWND::NULL, 0, 0, PM_REMOVE) }.as_bool());
+        assert_eq!(msg.message, WM_APP);
+        assert_eq!(
+            unsafe { reclaim_thread_payload::<Vec<String>>(msg.lParam) },
+            ["payload"]
+        );
+
+        // Thread without message queue.
+        assert!(post_thread_payload(0, WM_APP, 5_u64).is_err());
+
+        post_quit_with_code(thread_id, 3)?;
+        assert!(unsafe { PeekMessageW(&mut msg, HWND::NULL, 0, 0, PM_REMOVE) }.as_bool());
+        assert_eq!((msg.message, msg.wParam.0), (WM_QUIT, 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn message_name_lookup() {
+        assert_eq!(message_name(WM_CREATE), Some("WM_CREATE"));
+        assert_eq!(message_name(WM_DESTROY), Some("WM_DESTROY"));
+        assert_eq!(message_name(WM_PAINT), Some("WM_PAINT"));
+        assert_eq!(message_name(WM_TIMER), Some("WM_TIMER"));
+        assert_eq!(message_name(WM_USER + 1), Some("WM_USER + n"));
+        assert_eq!(message_name(WM_APP), Some("WM_APP + n"));
+        assert_eq!(message_name(0xc123), Some("registered message"));
+        assert_eq!(message_name(0x0004), None); // Unassigned, between `WM_MOVE` and `WM_SIZE`.
+        assert_eq!(message_name(0x1_0000), None);
+    }
 }